@@ -0,0 +1,272 @@
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Only the first segments of a key form prefixes, e.g. `a`, `a:b` and `a:b:c` for `a:b:c:d:e`.
+/// This bounds the depth only, `user:<id>:name` still yields one `user:<id>` prefix per id.
+const MAX_PREFIX_DEPTH: usize = 3;
+/// Prefixes tracked during a scan, keys under prefixes seen after that are counted in [`OTHER_PREFIXES`].
+const MAX_PREFIXES: usize = 10_000;
+/// Biggest prefixes kept in a report.
+const MAX_REPORTED_PREFIXES: usize = 1_000;
+/// Aggregate of the keys whose prefix was not tracked any more.
+pub const OTHER_PREFIXES: &str = "(other prefixes)";
+
+/// What the analyzer records about one key.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyStats {
+    pub key: String,
+    pub key_type: String,
+    /// `MEMORY USAGE` in bytes.
+    pub memory: u64,
+    /// Length of the value, e.g. `LLEN` of a list or `STRLEN` of a string.
+    pub length: u64,
+    /// Seconds to live, -1 for keys without expiry.
+    pub ttl: i64,
+}
+
+/// Keys and memory of a prefix or a type.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Aggregate {
+    pub name: String,
+    pub keys: u64,
+    pub memory: u64,
+}
+
+/// Snapshot of a keyspace analysis, aggregates are sorted by memory and prefixes cut to the biggest ones.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyspaceReport {
+    pub keys: u64,
+    pub memory: u64,
+    pub biggest_keys: Vec<KeyStats>,
+    pub prefixes: Vec<Aggregate>,
+    pub types: Vec<Aggregate>,
+    pub without_expiry: Aggregate,
+    pub biggest_keys_without_expiry: Vec<KeyStats>,
+}
+
+/// Accumulates [`KeyStats`] while the keyspace is scanned.
+#[derive(Clone, Debug)]
+pub struct KeyspaceAnalyzer {
+    separator: String,
+    top: usize,
+    keys: u64,
+    memory: u64,
+    biggest_keys: Vec<KeyStats>,
+    biggest_keys_without_expiry: Vec<KeyStats>,
+    prefixes: HashMap<String, (u64, u64)>,
+    other_prefixes: (u64, u64),
+    types: HashMap<String, (u64, u64)>,
+    without_expiry: (u64, u64),
+}
+
+impl KeyspaceAnalyzer {
+    /// Group keys by the tree `separator` and keep the `top` biggest keys.
+    pub fn new(separator: impl Into<String>, top: usize) -> Self {
+        Self {
+            separator: separator.into(),
+            top,
+            keys: 0,
+            memory: 0,
+            biggest_keys: vec![],
+            biggest_keys_without_expiry: vec![],
+            prefixes: HashMap::new(),
+            other_prefixes: (0, 0),
+            types: HashMap::new(),
+            without_expiry: (0, 0),
+        }
+    }
+
+    pub fn add(&mut self, stats: KeyStats) {
+        self.keys += 1;
+        self.memory += stats.memory;
+
+        let mut parts = stats.key.split(self.separator.as_str()).collect::<Vec<_>>();
+        parts.pop();
+        let mut prefix = String::new();
+        for (i, part) in parts.iter().take(MAX_PREFIX_DEPTH).enumerate() {
+            if i > 0 {
+                prefix.push_str(&self.separator);
+            }
+            prefix.push_str(part);
+            let (keys, memory) = match self.prefixes.get_mut(&prefix) {
+                Some(aggregate) => aggregate,
+                None if self.prefixes.len() < MAX_PREFIXES => self.prefixes.entry(prefix.clone()).or_default(),
+                None => {
+                    // deeper prefixes of this key are new as well
+                    self.other_prefixes.0 += 1;
+                    self.other_prefixes.1 += stats.memory;
+                    break;
+                }
+            };
+            *keys += 1;
+            *memory += stats.memory;
+        }
+
+        let (keys, memory) = self.types.entry(stats.key_type.clone()).or_default();
+        *keys += 1;
+        *memory += stats.memory;
+
+        if stats.ttl == -1 {
+            self.without_expiry.0 += 1;
+            self.without_expiry.1 += stats.memory;
+            push_top(&mut self.biggest_keys_without_expiry, stats.clone(), self.top);
+        }
+        push_top(&mut self.biggest_keys, stats, self.top);
+    }
+
+    pub fn report(&self) -> KeyspaceReport {
+        KeyspaceReport {
+            keys: self.keys,
+            memory: self.memory,
+            biggest_keys: self.biggest_keys.clone(),
+            prefixes: self.prefixes(),
+            types: aggregates(&self.types, self.types.len()),
+            without_expiry: Aggregate {
+                name: "without expiry".to_string(),
+                keys: self.without_expiry.0,
+                memory: self.without_expiry.1,
+            },
+            biggest_keys_without_expiry: self.biggest_keys_without_expiry.clone(),
+        }
+    }
+}
+
+impl KeyspaceAnalyzer {
+    fn prefixes(&self) -> Vec<Aggregate> {
+        let mut prefixes = aggregates(&self.prefixes, MAX_REPORTED_PREFIXES);
+        if self.other_prefixes.0 > 0 {
+            prefixes.push(Aggregate {
+                name: OTHER_PREFIXES.to_string(),
+                keys: self.other_prefixes.0,
+                memory: self.other_prefixes.1,
+            });
+        }
+        prefixes
+    }
+}
+
+impl KeyspaceReport {
+    /// One row per key, prefix and type, the `section` column tells them apart.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("section,name,type,keys,memory,length,ttl\n");
+        let mut push_key = |section: &str, stats: &KeyStats| {
+            csv.push_str(&format!(
+                "{section},{},{},1,{},{},{}\n",
+                csv_field(&stats.key),
+                csv_field(&stats.key_type),
+                stats.memory,
+                stats.length,
+                stats.ttl
+            ));
+        };
+        for stats in &self.biggest_keys {
+            push_key("key", stats);
+        }
+        for stats in &self.biggest_keys_without_expiry {
+            push_key("key_without_expiry", stats);
+        }
+        for (section, aggregate) in self
+            .prefixes
+            .iter()
+            .map(|aggregate| ("prefix", aggregate))
+            .chain(self.types.iter().map(|aggregate| ("type", aggregate)))
+        {
+            csv.push_str(&format!(
+                "{section},{},,{},{},,\n",
+                csv_field(&aggregate.name),
+                aggregate.keys,
+                aggregate.memory
+            ));
+        }
+        csv
+    }
+}
+
+/// Keep `list` sorted by memory with at most `top` entries.
+fn push_top(list: &mut Vec<KeyStats>, stats: KeyStats, top: usize) {
+    if list.len() >= top && list.last().is_none_or(|last| last.memory >= stats.memory) {
+        return;
+    }
+    let index = list.partition_point(|other| other.memory >= stats.memory);
+    list.insert(index, stats);
+    list.truncate(top);
+}
+
+/// The `limit` biggest entries of `map` by memory, only those are cloned and sorted.
+fn aggregates(map: &HashMap<String, (u64, u64)>, limit: usize) -> Vec<Aggregate> {
+    if limit == 0 {
+        return vec![];
+    }
+    let by_memory = |a: &(&String, &(u64, u64)), b: &(&String, &(u64, u64))| b.1.1.cmp(&a.1.1).then_with(|| a.0.cmp(b.0));
+    let mut entries = map.iter().collect::<Vec<_>>();
+    if entries.len() > limit {
+        entries.select_nth_unstable_by(limit - 1, by_memory);
+        entries.truncate(limit);
+    }
+    entries.sort_by(by_memory);
+    entries
+        .into_iter()
+        .map(|(name, (keys, memory))| Aggregate {
+            name: name.clone(),
+            keys: *keys,
+            memory: *memory,
+        })
+        .collect()
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::analyzer::{KeyStats, KeyspaceAnalyzer, MAX_PREFIXES, MAX_REPORTED_PREFIXES, OTHER_PREFIXES};
+
+    #[test]
+    fn test_analyze() {
+        let mut analyzer = KeyspaceAnalyzer::new(":", 2);
+        let key = |key: &str, key_type: &str, memory: u64, ttl: i64| KeyStats {
+            key: key.to_string(),
+            key_type: key_type.to_string(),
+            memory,
+            length: 1,
+            ttl,
+        };
+        analyzer.add(key("user:1:name", "string", 50, -1));
+        analyzer.add(key("user:2:name", "string", 70, 100));
+        analyzer.add(key("queue:jobs", "list", 300, -1));
+        analyzer.add(key("counter", "string", 10, -1));
+
+        let report = analyzer.report();
+        assert_eq!((report.keys, report.memory), (4, 430));
+        let biggest = report.biggest_keys.iter().map(|stats| stats.key.as_str()).collect::<Vec<_>>();
+        assert_eq!(biggest, ["queue:jobs", "user:2:name"]);
+        let prefixes = report
+            .prefixes
+            .iter()
+            .map(|aggregate| (aggregate.name.as_str(), aggregate.keys, aggregate.memory))
+            .collect::<Vec<_>>();
+        assert_eq!(prefixes, [("queue", 1, 300), ("user", 2, 120), ("user:2", 1, 70), ("user:1", 1, 50)]);
+        assert_eq!(report.types.first().map(|aggregate| aggregate.name.as_str()), Some("list"));
+        assert_eq!((report.without_expiry.keys, report.without_expiry.memory), (3, 360));
+
+        let mut analyzer = KeyspaceAnalyzer::new(":", 1);
+        analyzer.add(key("a,b", "hash", 1, -1));
+        assert!(analyzer.report().to_csv().contains("key,\"a,b\",hash,1,1,1,-1\n"));
+
+        // one `session:<id>` prefix per id, bounded once MAX_PREFIXES are tracked
+        let mut analyzer = KeyspaceAnalyzer::new(":", 1);
+        for id in 0..MAX_PREFIXES + 10 {
+            analyzer.add(key(&format!("session:{id}:data"), "hash", 1, -1));
+        }
+        let report = analyzer.report();
+        assert_eq!(report.prefixes.len(), MAX_REPORTED_PREFIXES + 1);
+        assert_eq!(report.prefixes.first().map(|aggregate| aggregate.name.as_str()), Some("session"));
+        let other = report.prefixes.last().map(|aggregate| (aggregate.name.as_str(), aggregate.keys));
+        assert_eq!(other, Some((OTHER_PREFIXES, 11)));
+    }
+}
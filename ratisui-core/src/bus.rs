@@ -105,10 +105,23 @@ pub enum GlobalEvent {
     // Force refresh ui
     Tick,
     ClientChanged,
+    /// Workspace name and its health.
+    ConnectionStatus(String, ConnectionStatus),
     #[allow(unused)]
     Dynamic(String),
 }
 
+/// Health of the active client, reported by the background health checker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum ConnectionStatus {
+    #[strum(serialize = "connected")]
+    Connected,
+    #[strum(serialize = "reconnecting")]
+    Reconnecting,
+    #[strum(serialize = "down")]
+    Down,
+}
+
 pub fn publish_event(event: GlobalEvent) -> Result<()> {
     GLOBAL_CHANNEL.tx.send(event).context("Publishing GlobalEvent failed")?;
     Ok(())
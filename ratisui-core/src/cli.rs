@@ -1,4 +1,7 @@
+use crate::configuration::Database;
+use anyhow::Result;
 use clap::{Parser, arg};
+use std::env;
 
 #[derive(Default, Clone, Debug, Parser)]
 #[command(name = "ratisui")]
@@ -12,6 +15,32 @@ pub struct AppArguments {
     )]
     pub target: Option<String>,
 
+    #[arg(
+        short = 'u',
+        long = "url",
+        value_name = "URL",
+        conflicts_with = "target",
+        help = "Connect to redis[s]://[user[:password]@]host[:port][/db] without saving it"
+    )]
+    pub url: Option<String>,
+
+    #[arg(long = "host", value_name = "HOST", conflicts_with = "target", help = "Ad-hoc redis host")]
+    pub host: Option<String>,
+
+    #[arg(long = "port", value_name = "PORT", conflicts_with = "target", help = "Ad-hoc redis port")]
+    pub port: Option<u16>,
+
+    #[arg(long = "db", value_name = "DB", conflicts_with = "target", help = "Ad-hoc logical database")]
+    pub db: Option<u32>,
+
+    #[arg(
+        long = "user",
+        value_name = "USER",
+        conflicts_with = "target",
+        help = "Ad-hoc username, the password is read from REDISCLI_AUTH"
+    )]
+    pub user: Option<String>,
+
     #[arg(
         short = 'T',
         long = "theme",
@@ -22,4 +51,41 @@ pub struct AppArguments {
 
     #[arg(long = "once", help = "Will not load | save databases")]
     pub once: bool,
+
+    #[arg(long = "readonly", help = "Reject write operations on every database")]
+    pub readonly: bool,
+}
+
+impl AppArguments {
+    /// Connection described by `--url` and/or `--host`, `--port`, `--db`, `--user`.
+    pub fn ad_hoc_database(&self) -> Result<Option<Database>> {
+        if self.url.is_none()
+            && self.host.is_none()
+            && self.port.is_none()
+            && self.db.is_none()
+            && self.user.is_none()
+        {
+            return Ok(None);
+        }
+        let url = self.url.as_deref().unwrap_or("redis://127.0.0.1:6379");
+        let mut database = Database::from_url(url)?;
+        if let Some(host) = &self.host {
+            database.host = host.clone();
+        }
+        if let Some(port) = self.port {
+            database.port = port;
+        }
+        if let Some(db) = self.db {
+            database.db = db;
+        }
+        if let Some(user) = &self.user {
+            database.username = Some(user.clone());
+        }
+        if database.password.is_none()
+            && let Ok(password) = env::var("REDISCLI_AUTH")
+        {
+            database.password = Some(password);
+        }
+        Ok(Some(database))
+    }
 }
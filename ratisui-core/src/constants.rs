@@ -1 +1,3 @@
-pub static DATE_FORMAT_PATTERN: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
\ No newline at end of file
+pub static DATE_FORMAT_PATTERN: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+/// Separator of the key tree in the explorer and of the analyzer prefixes.
+pub static KEY_SEPARATOR: &str = ":";
@@ -0,0 +1,176 @@
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::Argon2;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::sync::RwLock;
+use tokio::process::Command;
+
+/// Marks a value sealed with the master passphrase, anything else is a legacy base64 value.
+const PREFIX: &str = "enc:";
+/// Password read from an environment variable at connect time, e.g. `env:REDIS_PASSWORD`.
+pub const ENV_PREFIX: &str = "env:";
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+/// Known plaintext sealed next to the salt, to tell a wrong passphrase apart from a corrupted file.
+const VERIFIER: &str = "ratisui";
+
+/// Key derivation parameters stored at the top of `databases.ron`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Encryption {
+    pub salt: String,
+    pub verifier: String,
+}
+
+static CIPHER: Lazy<RwLock<Option<(ChaCha20Poly1305, Encryption)>>> = Lazy::new(|| RwLock::new(None));
+
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(PREFIX)
+}
+
+pub fn is_unlocked() -> bool {
+    CIPHER.read().is_ok_and(|cipher| cipher.is_some())
+}
+
+/// Start a new store with a fresh salt.
+pub fn init(passphrase: &str) -> Result<()> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = derive(passphrase, &salt)?;
+    let encryption = Encryption {
+        salt: BASE64_STANDARD.encode(salt),
+        verifier: seal(&cipher, VERIFIER)?,
+    };
+    set_cipher(Some((cipher, encryption)))
+}
+
+/// Derive the key of an existing store, fails if the passphrase does not match.
+pub fn unlock(passphrase: &str, encryption: &Encryption) -> Result<()> {
+    let salt = BASE64_STANDARD
+        .decode(&encryption.salt)
+        .context("decode salt error")?;
+    let cipher = derive(passphrase, &salt)?;
+    if open(&cipher, &encryption.verifier).ok().as_deref() != Some(VERIFIER) {
+        bail!("Wrong master passphrase");
+    }
+    set_cipher(Some((cipher, encryption.clone())))
+}
+
+/// Forget the key, credentials are saved in plain base64 again.
+pub fn lock() -> Result<()> {
+    set_cipher(None)
+}
+
+pub fn encryption() -> Option<Encryption> {
+    CIPHER
+        .read()
+        .ok()
+        .and_then(|cipher| cipher.as_ref().map(|(_, encryption)| encryption.clone()))
+}
+
+pub fn encrypt(plain: &str) -> Result<String> {
+    let guard = CIPHER.read().map_err(|e| anyhow!("{}", e))?;
+    let (cipher, _) = guard.as_ref().context("credential store is locked")?;
+    seal(cipher, plain)
+}
+
+pub fn decrypt(value: &str) -> Result<String> {
+    let guard = CIPHER.read().map_err(|e| anyhow!("{}", e))?;
+    let (cipher, _) = guard
+        .as_ref()
+        .context("databases.ron is encrypted but the credential store is locked")?;
+    open(cipher, value)
+}
+
+/// Value of a password given as `env:VAR_NAME` or printed by a password command,
+/// the command wins when both are set.
+pub async fn resolve_password(password: Option<String>, command: Option<String>) -> Result<Option<String>> {
+    if let Some(command) = command.filter(|command| !command.trim().is_empty()) {
+        return run_password_command(&command).await.map(Some);
+    }
+    match password {
+        Some(password) => match password.strip_prefix(ENV_PREFIX) {
+            Some(var) => env::var(var)
+                .with_context(|| format!("environment variable {var} is not set"))
+                .map(Some),
+            None => Ok(Some(password)),
+        },
+        None => Ok(None),
+    }
+}
+
+async fn run_password_command(command: &str) -> Result<String> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").arg("/C").arg(command).output().await
+    } else {
+        Command::new("sh").arg("-c").arg(command).output().await
+    }
+    .with_context(|| format!("failed to run password command `{command}`"))?;
+    if !output.status.success() {
+        bail!(
+            "password command `{command}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let password = String::from_utf8(output.stdout)?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn set_cipher(cipher: Option<(ChaCha20Poly1305, Encryption)>) -> Result<()> {
+    let mut guard = CIPHER.write().map_err(|e| anyhow!("{}", e))?;
+    *guard = cipher;
+    Ok(())
+}
+
+fn derive(passphrase: &str, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Failed to derive key: {}", e))?;
+    Ok(ChaCha20Poly1305::new(Key::from_slice(&key)))
+}
+
+fn seal(cipher: &ChaCha20Poly1305, plain: &str) -> Result<String> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let sealed = cipher
+        .encrypt(&nonce, plain.as_bytes())
+        .map_err(|e| anyhow!("Failed to encrypt: {}", e))?;
+    let mut bytes = nonce.to_vec();
+    bytes.extend(sealed);
+    Ok(format!("{PREFIX}{}", BASE64_STANDARD.encode(bytes)))
+}
+
+fn open(cipher: &ChaCha20Poly1305, value: &str) -> Result<String> {
+    let encoded = value.strip_prefix(PREFIX).context("value is not encrypted")?;
+    let bytes = BASE64_STANDARD.decode(encoded).context("decode base64 error")?;
+    let (nonce, sealed) = bytes
+        .split_at_checked(NONCE_LEN)
+        .context("encrypted value is too short")?;
+    let plain = cipher
+        .decrypt(Nonce::from_slice(nonce), sealed)
+        .map_err(|_| anyhow!("Failed to decrypt, wrong master passphrase?"))?;
+    Ok(String::from_utf8(plain)?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::credentials::{derive, is_encrypted, open, seal};
+    use anyhow::Result;
+
+    #[test]
+    fn test_seal_open() -> Result<()> {
+        let cipher = derive("passphrase", b"0123456789abcdef")?;
+        let sealed = seal(&cipher, "secret")?;
+        assert!(is_encrypted(&sealed));
+        assert_eq!(open(&cipher, &sealed)?, "secret");
+
+        let other = derive("wrong", b"0123456789abcdef")?;
+        assert!(open(&other, &sealed).is_err());
+        Ok(())
+    }
+}
@@ -100,6 +100,7 @@ impl HighlightProcessor {
                 ContentType::String => self.process_plain()?,
                 ContentType::Json => self.process_json()?,
                 ContentType::Xml => self.process_xml()?,
+                ContentType::Lua => self.process_lua()?,
                 ContentType::JavaSerialized |
                 ContentType::Protobuf |
                 ContentType::Ron => self.process_ron()?,
@@ -432,6 +433,82 @@ impl HighlightProcessor {
         Ok(true)
     }
 
+    /// Scripts are highlighted even while they do not parse yet, they are being edited.
+    fn process_lua(&mut self) -> Result<bool> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_lua::LANGUAGE.into())?;
+        let tree = parser
+            .parse(self.source.as_str(), self.tree.as_ref())
+            .context("parse error")?;
+        self.tree = Some(tree);
+
+        let mut highlight_config = HighlightConfiguration::new(
+            tree_sitter_lua::LANGUAGE.into(),
+            "lua",
+            tree_sitter_lua::HIGHLIGHTS_QUERY,
+            "",
+            "",
+        )?;
+
+        let highlight_names = vec![
+            "property",         // 0
+            "keyword",          // 1
+            "function",         // 2
+            "comment",          // 3
+            "constant",         // 4
+            "string",           // 5
+            "number",           // 6
+            "boolean",          // 7
+            "variable.builtin", // 8
+        ];
+        highlight_config.configure(&highlight_names);
+
+        let mut highlighter = Highlighter::new();
+        let highlights =
+            highlighter.highlight(&highlight_config, self.source.as_bytes(), None, |_| None)?;
+        let mut fragments: Vec<HighlightText> = vec![];
+        let mut highlight_text: Option<HighlightText> = None;
+        for event in highlights {
+            match event? {
+                HighlightEvent::Source { start, end } => {
+                    let x = &self.source[start..end];
+                    match highlight_text {
+                        None => fragments.push(HighlightText {
+                            text: x.to_string(),
+                            kind: HighlightKind::Unknown,
+                        }),
+                        Some(ref mut ht) => ht.text = x.to_string(),
+                    }
+                }
+                HighlightEvent::HighlightStart(s) => {
+                    let mut ht = HighlightText {
+                        text: "".to_string(),
+                        kind: HighlightKind::Unknown,
+                    };
+                    match s.0 {
+                        0 | 2 => ht.kind = HighlightKind::Property,
+                        1 => ht.kind = HighlightKind::Keyword,
+                        3 => ht.kind = HighlightKind::Comment,
+                        4 | 8 => ht.kind = HighlightKind::Constant,
+                        5 => ht.kind = HighlightKind::String,
+                        6 => ht.kind = HighlightKind::Number,
+                        7 => ht.kind = HighlightKind::Boolean,
+                        _ => ht.kind = HighlightKind::Unknown,
+                    }
+                    highlight_text = Some(ht);
+                }
+                HighlightEvent::HighlightEnd => {
+                    if let Some(ref mut ht) = highlight_text {
+                        fragments.push(ht.clone());
+                        highlight_text = None;
+                    }
+                }
+            }
+        }
+        self.fragments = fragments;
+        Ok(true)
+    }
+
     fn process_plain(&mut self) -> Result<bool> {
         let mut fragments = vec![];
         fragments.push(HighlightText {
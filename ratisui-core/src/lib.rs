@@ -19,6 +19,7 @@
 
 pub mod redis_opt;
 pub mod configuration;
+pub mod credentials;
 pub mod utils;
 pub mod bus;
 pub mod ssh_tunnel;
@@ -30,5 +31,7 @@ pub mod input;
 pub mod highlight_value;
 pub mod serde_wrapper;
 pub mod mouse;
+pub mod server;
+pub mod analyzer;
 
 mod notify_mutex;
@@ -1,23 +1,31 @@
-use crate::bus::{publish_event, publish_msg, GlobalEvent, Message};
-use crate::configuration::{to_protocol_version, Database};
+use crate::analyzer::KeyStats;
+use crate::bus::{publish_event, publish_msg, ConnectionStatus, GlobalEvent, Message};
+use crate::configuration::{to_protocol_version, Database, Sentinel};
+use crate::server::{keyevent_flags, parse_latency_history, ClientInfo, ConfigParam, LatencyEvent, SlowlogEntry};
 use crate::ssh_tunnel::SshTunnel;
 use crate::utils::split_args;
 use anyhow::{anyhow, bail, Context, Error, Result};
-use deadpool_redis::redis::ConnectionAddr::{Tcp, TcpTls};
+use deadpool_redis::redis::cluster::ClusterClientBuilder;
+use deadpool_redis::redis::cluster_async::ClusterConnection;
+use deadpool_redis::redis::io::AsyncDNSResolver;
+use deadpool_redis::redis::ConnectionAddr::{Tcp, TcpTls, Unix};
 use deadpool_redis::redis::{
-    cmd, Arg, AsyncCommands, AsyncIter, Client, Cmd, ConnectionInfo, ConnectionLike,
-    FromRedisValue, JsonAsyncCommands, Pipeline, RedisConnectionInfo, RedisFuture, ScanOptions, ToRedisArgs,
-    Value, VerbatimFormat,
+    cmd, Arg, AsyncCommands, Client, ClientTlsConfig, Cmd, ConnectionAddr, ConnectionInfo, ConnectionLike,
+    FromRedisValue, JsonAsyncCommands, Pipeline, RedisConnectionInfo, RedisFuture, ToRedisArgs,
+    TlsCertificates, Value, VerbatimFormat,
 };
 use deadpool_redis::{Pool, Runtime};
 use futures::future::join_all;
 use futures::StreamExt;
-use log::info;
+use log::{info, warn};
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
 use std::future::Future;
-use std::ops::DerefMut;
-use std::sync::RwLock;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once, RwLock};
 use std::task::Poll;
 use std::time::{Duration, Instant};
 use crossbeam_channel::Sender;
@@ -106,6 +114,22 @@ where
 
 static REDIS_OPERATIONS: Lazy<RwLock<Option<RedisOperations>>> = Lazy::new(|| RwLock::new(None));
 
+/// Workspaces that stay connected in the background, in the order they were opened.
+static BACKGROUND_OPERATIONS: Lazy<RwLock<Vec<RedisOperations>>> = Lazy::new(|| RwLock::new(vec![]));
+
+static FORCE_READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Treat every database as read-only, regardless of its own setting.
+pub fn force_read_only(read_only: bool) {
+    FORCE_READ_ONLY.store(read_only, Ordering::Relaxed);
+}
+
+/// Whether the active client must not be written to.
+pub fn is_read_only() -> bool {
+    FORCE_READ_ONLY.load(Ordering::Relaxed)
+        || redis_operations().is_some_and(|operations| operations.database.read_only)
+}
+
 pub fn redis_operations() -> Option<RedisOperations> {
     match REDIS_OPERATIONS.read() {
         Ok(guard) => guard.clone(),
@@ -113,27 +137,22 @@ pub fn redis_operations() -> Option<RedisOperations> {
     }
 }
 
+/// Connect to `database` as the active workspace, a server already open in the background is brought to the front.
 pub fn switch_client(name: impl Into<String>, database: &Database) -> Result<()> {
     let name = name.into();
+    if activate_workspace(&name)? {
+        return Ok(());
+    }
+    reconnect_client(name, database)
+}
+
+/// Connect in the background and replace the workspace of the same name, or open a new one.
+fn reconnect_client(name: String, database: &Database) -> Result<()> {
     let database = database.clone();
     tokio::spawn(async move {
         let result = async {
-            let (pool, client, tunnel) = build_pool(&database).await?;
-            let mut operation = RedisOperations::new(name, database.clone(), pool, client, tunnel);
-            operation.initialize().await?;
-            let result = REDIS_OPERATIONS.write();
-            match result {
-                Ok(mut x) => {
-                    if let Some(o) = x.deref_mut() {
-                        o.close();
-                    }
-                    *x = Some(operation);
-                }
-                Err(e) => {
-                    bail!("Failed to switch client: {}", e);
-                }
-            }
-            let _ = publish_event(GlobalEvent::ClientChanged);
+            let operation = connect(name, &database).await?;
+            replace_operations(operation)?;
             Ok::<(), Error>(())
         }
         .await;
@@ -143,13 +162,115 @@ pub fn switch_client(name: impl Into<String>, database: &Database) -> Result<()>
                 let _ = publish_msg(Message::info("Connected".to_string()));
             }
             Err(e) => {
-                let _ = publish_msg(Message::error(format!("Failed to switch client: {}", e)));
+                let _ = publish_msg(Message::error(format!("Failed to switch client: {:#}", e)));
             }
         }
     });
     Ok(())
 }
 
+/// Reconnect the active workspace to another logical database, the saved profile stays untouched.
+pub fn select_db(db: u32) -> Result<()> {
+    let operations = redis_operations().context("redis not connected")?;
+    if operations.is_cluster() {
+        bail!("Cluster mode only supports db 0");
+    }
+    let mut database = operations.profile.clone();
+    database.db = db;
+    switch_client(operations.name.clone(), &database)
+}
+
+/// Open the tunnel, discover the topology and start the background watchers.
+async fn connect(name: String, profile: &Database) -> Result<RedisOperations> {
+    let database = profile.resolve_credentials().await?;
+    let (pool, client, tunnel) = build_pool(&database).await?;
+    let mut operation = RedisOperations::new(name, profile.clone(), database, pool, client, tunnel);
+    operation.initialize().await?;
+    operation.watch_sentinel();
+    operation.watch_health();
+    Ok(operation)
+}
+
+/// Put the connection in its workspace, a new workspace becomes the active one
+/// while the previous active workspace keeps running in the background.
+fn replace_operations(operation: RedisOperations) -> Result<()> {
+    let name = operation.name.clone();
+    let activated = {
+        let mut active = REDIS_OPERATIONS.write().map_err(|e| anyhow!("Failed to switch client: {}", e))?;
+        let mut background = BACKGROUND_OPERATIONS.write().map_err(|e| anyhow!("Failed to switch client: {}", e))?;
+        if let Some(o) = background.iter_mut().find(|o| o.name == name) {
+            // reconnected while in the background
+            o.close();
+            *o = operation;
+            false
+        } else {
+            if let Some(mut o) = active.take() {
+                if o.name == name {
+                    o.close();
+                } else {
+                    background.push(o);
+                }
+            }
+            *active = Some(operation);
+            true
+        }
+    };
+    // publish without holding the locks, the ui reads the operations when handling events
+    if activated {
+        let _ = publish_event(GlobalEvent::ClientChanged);
+    }
+    let _ = publish_event(GlobalEvent::ConnectionStatus(name, ConnectionStatus::Connected));
+    Ok(())
+}
+
+/// Names of all open workspaces, the active one first.
+pub fn workspaces() -> Vec<String> {
+    let mut names = vec![];
+    if let Ok(active) = REDIS_OPERATIONS.read()
+        && let Some(o) = active.as_ref()
+    {
+        names.push(o.name.clone());
+    }
+    if let Ok(background) = BACKGROUND_OPERATIONS.read() {
+        names.extend(background.iter().map(|o| o.name.clone()));
+    }
+    names
+}
+
+/// Bring a background workspace to the front, returns false if there is no such workspace.
+pub fn activate_workspace(name: &str) -> Result<bool> {
+    {
+        let mut active = REDIS_OPERATIONS.write().map_err(|e| anyhow!("Failed to switch workspace: {}", e))?;
+        let mut background = BACKGROUND_OPERATIONS.write().map_err(|e| anyhow!("Failed to switch workspace: {}", e))?;
+        let Some(position) = background.iter().position(|o| o.name == name) else {
+            return Ok(false);
+        };
+        let operation = background.remove(position);
+        if let Some(o) = active.take() {
+            background.push(o);
+        }
+        *active = Some(operation);
+    }
+    publish_event(GlobalEvent::ClientChanged)?;
+    Ok(true)
+}
+
+/// Disconnect the active workspace, the oldest background workspace takes its place.
+pub fn close_workspace() -> Result<()> {
+    {
+        let mut active = REDIS_OPERATIONS.write().map_err(|e| anyhow!("Failed to close workspace: {}", e))?;
+        let mut background = BACKGROUND_OPERATIONS.write().map_err(|e| anyhow!("Failed to close workspace: {}", e))?;
+        if let Some(mut o) = active.take() {
+            o.close();
+        }
+        if !background.is_empty() {
+            *active = Some(background.remove(0));
+        }
+    }
+    publish_event(GlobalEvent::ClientChanged)?;
+    Ok(())
+}
+
 #[allow(unused)]
 fn build_client(database: &Database) -> Result<Client> {
     let mut client = Client::open(ConnectionInfo {
@@ -168,9 +289,57 @@ fn build_client(database: &Database) -> Result<Client> {
     }
 }
 
-async fn build_pool(database: &Database) -> Result<(Pool, Client, Option<SshTunnel>)> {
-    let mut ssh_tunnel_option = None;
-    let addr = if database.use_ssh_tunnel
+/// Ask each sentinel in turn for the current master address of `sentinel.master_name`.
+async fn resolve_sentinel_master(sentinel: &Sentinel) -> Result<(String, u16)> {
+    let mut last_error = anyhow!("no sentinel address configured");
+    for (host, port) in sentinel.parse_addresses()? {
+        let result = async {
+            let client = Client::open(ConnectionInfo {
+                addr: Tcp(host.clone(), port),
+                redis: RedisConnectionInfo::default(),
+            })?;
+            let mut connection = tokio::time::timeout(
+                Duration::from_secs(3),
+                client.get_multiplexed_async_connection(),
+            )
+            .await
+            .context("sentinel connection timed out")??;
+            let master: Option<(String, u16)> = cmd("SENTINEL")
+                .arg("get-master-addr-by-name")
+                .arg(&sentinel.master_name)
+                .query_async(&mut connection)
+                .await?;
+            master.context(format!("unknown master name: {}", sentinel.master_name))
+        }
+        .await;
+        match result {
+            Ok(master) => return Ok(master),
+            Err(e) => {
+                warn!("Sentinel {}:{} unavailable: {}", host, port, e);
+                last_error = e;
+            }
+        }
+    }
+    Err(last_error)
+}
+
+/// Figure out where to connect, opening an SSH tunnel first if one is configured.
+async fn resolve_addr(database: &Database) -> Result<(ConnectionAddr, Option<SshTunnel>)> {
+    if database.use_unix_socket
+        && let Some(path) = &database.unix_socket_path
+    {
+        return Ok((Unix(PathBuf::from(path)), None));
+    }
+    let (host, port) = if database.use_sentinel
+        && let Some(sentinel) = &database.sentinel
+    {
+        let (host, port) = resolve_sentinel_master(sentinel).await?;
+        info!("Sentinel master '{}' resolved to {}:{}", sentinel.master_name, host, port);
+        (host, port)
+    } else {
+        (database.host.clone(), database.port)
+    };
+    if database.use_ssh_tunnel
         && let Some(tunnel) = &database.ssh_tunnel
     {
         let mut ssh_tunnel = SshTunnel::new(
@@ -178,21 +347,27 @@ async fn build_pool(database: &Database) -> Result<(Pool, Client, Option<SshTunn
             tunnel.port,
             tunnel.username.clone(),
             tunnel.password.clone(),
-            database.host.clone(),
-            database.port,
-        );
+            host,
+            port,
+        )
+        .auth(tunnel.auth.clone())
+        .known_hosts(tunnel.known_hosts.clone())
+        .jump_hosts(tunnel.jump_hosts.clone());
         let addr = ssh_tunnel.open().await?;
         info!(
             "SSH-Tunnel listening on: {} <==> {}:{}",
             addr, tunnel.host, tunnel.port
         );
-        ssh_tunnel_option = Some(ssh_tunnel);
-        Tcp(addr.ip().to_string(), addr.port())
+        Ok((Tcp(addr.ip().to_string(), addr.port()), Some(ssh_tunnel)))
     } else {
-        Tcp(database.host.clone(), database.port)
-    };
+        Ok((Tcp(host, port), None))
+    }
+}
+
+async fn build_pool(database: &Database) -> Result<(Pool, Client, Option<SshTunnel>)> {
+    let (addr, ssh_tunnel_option) = resolve_addr(database).await?;
     let info = ConnectionInfo {
-        addr,
+        addr: with_tls(addr, database),
         redis: RedisConnectionInfo {
             db: i64::from(database.db),
             username: database.username.clone(),
@@ -200,17 +375,62 @@ async fn build_pool(database: &Database) -> Result<(Pool, Client, Option<SshTunn
             protocol: to_protocol_version(database.protocol.clone()),
         },
     };
-    let config = deadpool_redis::Config::from_connection_info(
-        deadpool_redis::ConnectionInfo::from(info.clone()),
-    );
-    let pool = config.create_pool(Some(Runtime::Tokio1))?;
-    Ok((pool, Client::open(info)?, ssh_tunnel_option))
+    let client = open_client(info, database)?;
+    // build the manager from the client's connection info, so certificates are kept
+    let manager = deadpool_redis::Manager::new(client.get_connection_info().clone())?;
+    let pool = Pool::builder(manager).runtime(Runtime::Tokio1).build()?;
+    Ok((pool, client, ssh_tunnel_option))
+}
+
+fn with_tls(addr: ConnectionAddr, database: &Database) -> ConnectionAddr {
+    match addr {
+        Tcp(host, port) if database.use_tls => TcpTls {
+            host,
+            port,
+            insecure: database.tls.as_ref().is_some_and(|tls| tls.insecure),
+            tls_params: None,
+        },
+        addr => addr,
+    }
+}
+
+fn open_client(info: ConnectionInfo, database: &Database) -> Result<Client> {
+    if !matches!(info.addr, TcpTls { .. }) {
+        return Ok(Client::open(info)?);
+    }
+    install_crypto_provider();
+    let Some(tls) = &database.tls else {
+        return Ok(Client::open(info)?);
+    };
+    let root_cert = tls
+        .ca_cert_path
+        .as_ref()
+        .map(|path| fs::read(path).context(format!("failed to read CA certificate: {path}")))
+        .transpose()?;
+    let client_tls = match (&tls.client_cert_path, &tls.client_key_path) {
+        (Some(cert), Some(key)) => Some(ClientTlsConfig {
+            client_cert: fs::read(cert).context(format!("failed to read client certificate: {cert}"))?,
+            client_key: fs::read(key).context(format!("failed to read client key: {key}"))?,
+        }),
+        (None, None) => None,
+        _ => bail!("client certificate and key must be configured together"),
+    };
+    Ok(Client::build_with_tls(info, TlsCertificates { client_tls, root_cert })?)
+}
+
+fn install_crypto_provider() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    });
 }
 
 #[derive(Clone)]
 pub struct RedisOperations {
     #[allow(unused)]
     pub name: String,
+    /// As configured, password commands and `env:` secrets are resolved again on every reconnect.
+    profile: Database,
     database: Database,
     pool: Pool,
     client: Client,
@@ -220,6 +440,10 @@ pub struct RedisOperations {
     is_cluster: bool,
     nodes: HashMap<String, NodeClientHolder>,
     cluster_pool: Option<deadpool_redis::cluster::Pool>,
+    /// Used instead of `cluster_pool` when nodes are reached through ssh tunnels.
+    cluster_connection: Option<ClusterConnection>,
+    sentinel_watcher: Option<Arc<tokio::sync::watch::Sender<bool>>>,
+    health_checker: Option<Arc<tokio::sync::watch::Sender<bool>>>,
 }
 
 #[derive(Clone, Debug)]
@@ -233,6 +457,7 @@ struct NodeClientHolder {
 impl RedisOperations {
     fn new(
         name: impl Into<String>,
+        profile: Database,
         database: Database,
         pool: Pool,
         client: Client,
@@ -240,6 +465,7 @@ impl RedisOperations {
     ) -> Self {
         Self {
             name: name.into(),
+            profile,
             database,
             pool,
             client,
@@ -249,11 +475,20 @@ impl RedisOperations {
             is_cluster: false,
             nodes: HashMap::new(),
             cluster_pool: None,
+            cluster_connection: None,
+            sentinel_watcher: None,
+            health_checker: None,
         }
     }
 
     fn close(&mut self) {
         self.pool.close();
+        if let Some(ref watcher) = self.sentinel_watcher {
+            let _ = watcher.send(true);
+        }
+        if let Some(ref checker) = self.health_checker {
+            let _ = checker.send(true);
+        }
         if let Some(ref ssh_tunnel) = self.ssh_tunnel {
             let mut tunnel = ssh_tunnel.clone();
             tokio::spawn(async move { tunnel.close().await });
@@ -270,10 +505,128 @@ impl RedisOperations {
         }
     }
 
+    pub fn database(&self) -> &Database {
+        &self.database
+    }
+
     pub fn is_cluster(&self) -> bool {
         self.is_cluster
     }
 
+    /// Poll the sentinels and reconnect to the new master once a failover is observed.
+    fn watch_sentinel(&mut self) {
+        if !self.database.use_sentinel {
+            return;
+        }
+        let Some(sentinel) = self.database.sentinel.clone() else {
+            return;
+        };
+        let (tx, mut rx) = tokio::sync::watch::channel(false);
+        self.sentinel_watcher = Some(Arc::new(tx));
+        let name = self.name.clone();
+        let database = self.profile.clone();
+        tokio::spawn(async move {
+            let mut master = resolve_sentinel_master(&sentinel).await.ok();
+            let mut check_interval = interval(Duration::from_secs(5));
+            loop {
+                tokio::select! {
+                    _ = rx.changed() => break,
+                    _ = check_interval.tick() => {}
+                }
+                match resolve_sentinel_master(&sentinel).await {
+                    Ok(current) => {
+                        if let Some(ref previous) = master
+                            && previous != &current
+                        {
+                            info!(
+                                "Sentinel failover: {}:{} -> {}:{}",
+                                previous.0, previous.1, current.0, current.1
+                            );
+                            let _ = publish_msg(Message::warning(format!(
+                                "Master moved to {}:{}, reconnecting",
+                                current.0, current.1
+                            )).title("Sentinel"));
+                            let _ = reconnect_client(name.clone(), &database);
+                            break;
+                        }
+                        master = Some(current);
+                    }
+                    Err(e) => warn!("Failed to resolve sentinel master: {}", e),
+                }
+            }
+        });
+    }
+
+    /// PING the server periodically, rebuild the whole client with backoff once it stops answering.
+    fn watch_health(&mut self) {
+        let (tx, mut rx) = tokio::sync::watch::channel(false);
+        self.health_checker = Some(Arc::new(tx));
+        let operations = self.clone();
+        tokio::spawn(async move {
+            let mut check_interval = interval(Duration::from_secs(5));
+            loop {
+                tokio::select! {
+                    _ = rx.changed() => return,
+                    _ = check_interval.tick() => {}
+                }
+                let ping = tokio::time::timeout(Duration::from_secs(3), operations.ping()).await;
+                match ping {
+                    Ok(Ok(_)) => continue,
+                    Ok(Err(e)) => warn!("Health check failed: {}", e),
+                    Err(_) => warn!("Health check timed out"),
+                }
+                break;
+            }
+            let _ = publish_event(GlobalEvent::ConnectionStatus(operations.name.clone(), ConnectionStatus::Reconnecting));
+            let mut backoff = Duration::from_secs(1);
+            let mut attempts = 0;
+            loop {
+                tokio::select! {
+                    _ = rx.changed() => return,
+                    _ = tokio::time::sleep(backoff) => {}
+                }
+                attempts += 1;
+                match connect(operations.name.clone(), &operations.profile).await {
+                    Ok(operation) => {
+                        if rx.has_changed().unwrap_or(true) {
+                            // switched to another server meanwhile
+                            let mut operation = operation;
+                            operation.close();
+                            return;
+                        }
+                        if let Err(e) = replace_operations(operation) {
+                            warn!("{}", e);
+                        }
+                        let _ = publish_msg(Message::info("Reconnected".to_string()));
+                        return;
+                    }
+                    Err(e) => {
+                        warn!("Reconnect attempt {attempts} failed: {:#}", e);
+                        if attempts == 3 {
+                            let _ = publish_event(GlobalEvent::ConnectionStatus(operations.name.clone(), ConnectionStatus::Down));
+                            let _ = publish_msg(Message::error(format!("Connection lost: {:#}", e)));
+                        }
+                        backoff = (backoff * 2).min(Duration::from_secs(30));
+                    }
+                }
+            }
+        });
+    }
+
+    /// Not going through `IConnection`, health checks should not flood the command log.
+    async fn ping(&self) -> Result<()> {
+        if self.is_cluster() {
+            for node in self.nodes.values() {
+                let mut connection = node.pool.get().await?;
+                let _: String = cmd("PING").query_async(&mut connection).await?;
+            }
+        } else {
+            let mut connection = self.pool.get().await?;
+            let _: String = cmd("PING").query_async(&mut connection).await?;
+        }
+        Ok(())
+    }
+
     fn print(&self) {
         if self.is_cluster() {
             info!("Cluster mode");
@@ -381,27 +734,30 @@ impl RedisOperations {
                 let mut database = Database::from(self.database.clone());
                 database.host = host;
                 database.port = port;
+                database.use_sentinel = false;
+                database.use_unix_socket = false;
                 let is_master = node_kind_map.get(&id).unwrap_or(&false);
                 let future = async move {
-                    if let Ok((pool, client, tunnel)) = build_pool(&database).await {
-                        Ok((
-                            id,
-                            NodeClientHolder {
-                                pool,
-                                client,
-                                ssh_tunnel: tunnel,
-                                is_master: *is_master,
-                            },
-                        ))
-                    } else {
-                        bail!("Failed to initialize node")
-                    }
+                    let (pool, client, tunnel) = build_pool(&database)
+                        .await
+                        .with_context(|| format!("failed to initialize node {}:{}", database.host, database.port))?;
+                    Ok::<_, Error>((
+                        id,
+                        NodeClientHolder {
+                            pool,
+                            client,
+                            ssh_tunnel: tunnel,
+                            is_master: *is_master,
+                        },
+                    ))
                 };
                 futures.push(future)
             }
             let results = join_all(futures).await;
             let mut cluster_urls = vec![];
-            for result in results {
+            let mut cluster_infos = vec![];
+            let mut tunneled_nodes: HashMap<(String, u16), SocketAddr> = HashMap::new();
+            for (result, (node_host, node_port, _)) in results.into_iter().zip(redis_nodes) {
                 let (id, node_holder) = result?;
                 let host;
                 let port;
@@ -418,18 +774,24 @@ impl RedisOperations {
                     }
                     _ => bail!("Not supported connection type"),
                 }
-                node_holders.insert(id, node_holder);
-
-                let addr = if self.database.use_tls {
-                    TcpTls {
+                let (host, port) = if node_holder.ssh_tunnel.is_some() {
+                    // keep the announced address, the resolver maps it to the forwarded port
+                    tunneled_nodes.insert((node_host.clone(), node_port), format!("{host}:{port}").parse()?);
+                    (node_host, node_port)
+                } else {
+                    (host, port)
+                };
+                let addr = match &node_holder.client.get_connection_info().addr {
+                    // keep the verification mode and certificates the node client was built with
+                    TcpTls { insecure, tls_params, .. } => TcpTls {
                         host,
                         port,
-                        insecure: true,
-                        tls_params: None,
-                    }
-                } else {
-                    Tcp(host, port)
+                        insecure: *insecure,
+                        tls_params: tls_params.clone(),
+                    },
+                    _ => Tcp(host, port),
                 };
+                node_holders.insert(id, node_holder);
                 let info = ConnectionInfo {
                     addr,
                     redis: RedisConnectionInfo {
@@ -439,9 +801,18 @@ impl RedisOperations {
                         protocol: to_protocol_version(self.database.protocol.clone()),
                     },
                 };
+                cluster_infos.push(info.clone());
                 cluster_urls.push(deadpool_redis::ConnectionInfo::from(info));
             }
             self.nodes = node_holders;
+            if !tunneled_nodes.is_empty() {
+                let client = ClusterClientBuilder::new(cluster_infos)
+                    .read_from_replicas()
+                    .async_dns_resolver(TunnelResolver(tunneled_nodes))
+                    .build()?;
+                self.cluster_connection = Some(client.get_async_connection().await?);
+                return Ok(());
+            }
             let config = deadpool_redis::cluster::Config {
                 urls: None,
                 connections: Some(cluster_urls),
@@ -474,6 +845,243 @@ impl RedisOperations {
         None
     }
 
+    /// Key statistics of every non-empty logical database.
+    pub async fn keyspace(&self) -> Result<Vec<Keyspace>> {
+        // cluster nodes only have db 0, ask the seed node like `initialize` does
+        let mut connection = self.get_standalone_connection().await?;
+        let info: String = Cmd::new()
+            .arg("INFO")
+            .arg("KEYSPACE")
+            .query_async(&mut connection)
+            .await?;
+        Ok(Keyspace::parse(&info))
+    }
+
+    /// Run the command on every cluster node, only on the masters when `masters_only`,
+    /// or on the server in standalone mode.
+    /// Not going through `IConnection`, panels polling the server should not flood the command log.
+    pub async fn node_cmd<V: FromRedisValue>(&self, cmd: &Cmd, masters_only: bool) -> Result<Vec<(Node, V)>> {
+        self.node_cmd_each(cmd, masters_only)
+            .await
+            .into_iter()
+            .map(|(node, reply)| reply.map(|v| (node, v)))
+            .collect()
+    }
+
+    /// Like [`RedisOperations::node_cmd`], one node failing does not hide the replies of the others.
+    pub async fn node_cmd_each<V: FromRedisValue>(&self, cmd: &Cmd, masters_only: bool) -> Vec<(Node, Result<V>)> {
+        if !self.is_cluster() {
+            let node = Node {
+                id: String::new(),
+                addr: self.client.get_connection_info().addr.to_string(),
+                is_master: true,
+            };
+            let reply = async {
+                let mut connection = self.pool.get().await?;
+                Ok::<V, Error>(cmd.query_async(&mut connection).await?)
+            }
+            .await;
+            return vec![(node, reply)];
+        }
+        let futures = self
+            .nodes
+            .iter()
+            .filter(|(_, holder)| holder.is_master || !masters_only)
+            .map(|(id, holder)| async move {
+                let node = Node {
+                    id: id.clone(),
+                    addr: holder.client.get_connection_info().addr.to_string(),
+                    is_master: holder.is_master,
+                };
+                let reply = async {
+                    let mut connection = holder.pool.get().await?;
+                    Ok::<V, Error>(cmd.query_async(&mut connection).await?)
+                }
+                .await;
+                (node, reply)
+            });
+        let mut replies = join_all(futures).await;
+        replies.sort_by(|(a, _), (b, _)| b.is_master.cmp(&a.is_master).then_with(|| a.addr.cmp(&b.addr)));
+        replies
+    }
+
+    /// Run a command on one node of [`RedisOperations::node_cmd`], found by its id in cluster mode.
+    pub async fn node_cmd_on<V: FromRedisValue>(&self, node: &Node, cmd: &Cmd) -> Result<V> {
+        let pool = if self.is_cluster() {
+            &self
+                .nodes
+                .get(&node.id)
+                .with_context(|| format!("node {} is not part of the cluster", node.addr))?
+                .pool
+        } else {
+            &self.pool
+        };
+        let mut connection = pool.get().await?;
+        Ok(cmd.query_async(&mut connection).await?)
+    }
+
+    /// `CLIENT LIST` of every node, replicas included.
+    pub async fn client_list(&self) -> Result<Vec<ClientInfo>> {
+        let mut command = cmd("CLIENT");
+        command.arg("LIST");
+        let replies = self.node_cmd::<String>(&command, false).await?;
+        Ok(replies
+            .iter()
+            .flat_map(|(node, list)| ClientInfo::parse(node, list))
+            .collect())
+    }
+
+    pub async fn client_kill(&self, client: &ClientInfo) -> Result<()> {
+        let mut command = cmd("CLIENT");
+        command.arg("KILL").arg("ID").arg(client.id);
+        let killed: u64 = self.node_cmd_on(&client.node, &command).await?;
+        if killed == 0 {
+            bail!("client {} is already gone", client.id);
+        }
+        Ok(())
+    }
+
+    /// `LATENCY LATEST` of every master, each event tagged with its node.
+    pub async fn latency_latest(&self) -> Result<Vec<LatencyEvent>> {
+        let mut command = cmd("LATENCY");
+        command.arg("LATEST");
+        let replies = self.node_cmd::<Value>(&command, true).await?;
+        Ok(replies
+            .iter()
+            .flat_map(|(node, value)| LatencyEvent::parse(node, value))
+            .collect())
+    }
+
+    /// `LATENCY HISTORY` on the node that reported the event.
+    pub async fn latency_history(&self, event: &LatencyEvent) -> Result<Vec<(i64, u64)>> {
+        let mut command = cmd("LATENCY");
+        command.arg("HISTORY").arg(&event.event);
+        let value: Value = self.node_cmd_on(&event.node, &command).await?;
+        Ok(parse_latency_history(&value))
+    }
+
+    /// `LATENCY DOCTOR` and `MEMORY DOCTOR` reports of every master, headed by its address in cluster mode.
+    pub async fn doctor_reports(&self) -> Result<(String, String)> {
+        let mut latency = cmd("LATENCY");
+        latency.arg("DOCTOR");
+        let mut memory = cmd("MEMORY");
+        memory.arg("DOCTOR");
+        let join = |reports: Vec<(Node, String)>| {
+            if !self.is_cluster() {
+                return reports.into_iter().map(|(_, report)| report).collect::<String>();
+            }
+            reports
+                .into_iter()
+                .map(|(node, report)| format!("# {}\n{}", node.addr, report.trim_end()))
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        };
+        Ok((
+            join(self.node_cmd(&latency, true).await?),
+            join(self.node_cmd(&memory, true).await?),
+        ))
+    }
+
+    /// `latency-monitor-threshold` of every master in milliseconds, 0 when the monitor is off.
+    pub async fn latency_monitor_threshold(&self) -> Result<Vec<(Node, u64)>> {
+        let mut command = cmd("CONFIG");
+        command.arg("GET").arg("latency-monitor-threshold");
+        let replies = self.node_cmd::<HashMap<String, String>>(&command, true).await?;
+        Ok(replies
+            .into_iter()
+            .map(|(node, config)| {
+                let threshold = config
+                    .get("latency-monitor-threshold")
+                    .and_then(|threshold| threshold.parse().ok())
+                    .unwrap_or(0);
+                (node, threshold)
+            })
+            .collect())
+    }
+
+    /// Change `latency-monitor-threshold` on every master until it restarts, the config file is left untouched.
+    pub async fn set_latency_monitor_threshold(&self, millis: u64) -> Result<()> {
+        let mut command = cmd("CONFIG");
+        command.arg("SET").arg("latency-monitor-threshold").arg(millis);
+        per_node_result(self.node_cmd_each::<()>(&command, true).await)?;
+        Ok(())
+    }
+
+    /// `notify-keyspace-events` of every master.
+    pub async fn notify_keyspace_events(&self) -> Result<Vec<(Node, String)>> {
+        let mut command = cmd("CONFIG");
+        command.arg("GET").arg("notify-keyspace-events");
+        let replies = self.node_cmd::<HashMap<String, String>>(&command, true).await?;
+        Ok(replies
+            .into_iter()
+            .map(|(node, mut config)| (node, config.remove("notify-keyspace-events").unwrap_or_default()))
+            .collect())
+    }
+
+    /// Make every master publish keyevent notifications until it restarts, returns how many were changed.
+    pub async fn enable_keyevent_notifications(&self) -> Result<usize> {
+        let mut changed = 0;
+        for (node, current) in self.notify_keyspace_events().await? {
+            if let Some(flags) = keyevent_flags(&current) {
+                let mut command = cmd("CONFIG");
+                command.arg("SET").arg("notify-keyspace-events").arg(flags);
+                let _: () = self.node_cmd_on(&node, &command).await?;
+                changed += 1;
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Every parameter of `CONFIG GET *` on every master, the nodes [`RedisOperations::config_set`] writes to.
+    pub async fn config_params(&self) -> Result<Vec<ConfigParam>> {
+        let mut command = cmd("CONFIG");
+        command.arg("GET").arg("*");
+        let replies = self.node_cmd_each::<HashMap<String, String>>(&command, true).await;
+        let configs = per_node_result(replies)?;
+        let redis_version = self.get_server_info("redis_version");
+        Ok(ConfigParam::parse(configs, redis_version.as_deref()))
+    }
+
+    /// `CONFIG SET` on every master, the config file is left untouched until [`RedisOperations::config_rewrite`].
+    pub async fn config_set(&self, name: &str, value: &str) -> Result<()> {
+        let mut command = cmd("CONFIG");
+        command.arg("SET").arg(name).arg(value);
+        per_node_result(self.node_cmd_each::<()>(&command, true).await)?;
+        Ok(())
+    }
+
+    /// Write the running configuration to the config file of every master.
+    pub async fn config_rewrite(&self) -> Result<()> {
+        let mut command = cmd("CONFIG");
+        command.arg("REWRITE");
+        per_node_result(self.node_cmd_each::<()>(&command, true).await)?;
+        Ok(())
+    }
+
+    /// Latest `SLOWLOG GET` entries of every master.
+    pub async fn slowlog(&self, count: usize) -> Result<Vec<SlowlogEntry>> {
+        let mut command = cmd("SLOWLOG");
+        command.arg("GET").arg(count);
+        let replies = self.node_cmd::<Value>(&command, true).await?;
+        Ok(replies
+            .iter()
+            .flat_map(|(node, value)| SlowlogEntry::parse(&node.addr, value))
+            .collect())
+    }
+
+    pub async fn slowlog_reset(&self) -> Result<()> {
+        let mut command = cmd("SLOWLOG");
+        command.arg("RESET");
+        let _: Vec<(Node, ())> = self.node_cmd(&command, true).await?;
+        Ok(())
+    }
+
+    /// Libraries loaded with `FUNCTION LOAD`, with their code.
+    pub async fn function_list(&self) -> Result<Vec<FunctionLibrary>> {
+        let value: Value = self.str_cmd("FUNCTION LIST WITHCODE").await?;
+        Ok(FunctionLibrary::parse(&value))
+    }
+
     pub fn has_module<T: Into<String>>(&self, s: T) -> Result<bool> {
         let s = s.into();
         if let Some(modules_info) = &self.modules_info {
@@ -494,8 +1102,11 @@ impl RedisOperations {
     }
 
     async fn get_cluster_connection(&self) -> Result<IClusterConnection> {
+        if let Some(connection) = &self.cluster_connection {
+            return Ok(IClusterConnection::Tunneled(connection.clone()));
+        }
         let pool = &self.cluster_pool.clone().context("should be cluster")?;
-        Ok(IClusterConnection(pool.get().await?))
+        Ok(IClusterConnection::Pooled(pool.get().await?))
     }
 
     async fn get_standalone_connection(&self) -> Result<IConnection> {
@@ -520,6 +1131,24 @@ impl RedisOperations {
         }
     }
 
+    /// Send the commands together on one connection, wrapped in MULTI/EXEC when `atomic`.
+    pub async fn pipeline(&self, cmds: Vec<Cmd>, atomic: bool) -> Result<Value> {
+        let mut pipeline = Pipeline::new();
+        if atomic {
+            pipeline.atomic();
+        }
+        for cmd in cmds {
+            pipeline.add_command(cmd);
+        }
+        if self.is_cluster() {
+            let mut connection = self.get_cluster_connection().await?;
+            Ok(pipeline.query_async(&mut connection).await?)
+        } else {
+            let mut connection = self.get_standalone_connection().await?;
+            Ok(pipeline.query_async(&mut connection).await?)
+        }
+    }
+
     pub async fn monitor(&self, sender: Sender<Value>) -> Result<DisposableMonitor> {
         let (tx, rx) = tokio::sync::watch::channel(false);
         let (event_tx, event_rx) = tokio::sync::mpsc::unbounded_channel();
@@ -766,48 +1395,103 @@ impl RedisOperations {
     }
 
     pub async fn scan(&self, pattern: impl Into<String>, count: usize) -> Result<Vec<String>> {
-        let pattern = &pattern.into();
-        if self.is_cluster() {
-            let mut all_node_keys = Vec::new();
-            for (_, v) in &self.nodes {
-                if v.is_master {
-                    let mut connection = IConnection(v.pool.get().await?);
-                    let mut iter: AsyncIter<String> = connection
-                        .scan_options(
-                            ScanOptions::default()
-                                .with_pattern(pattern)
-                                .with_count(count),
-                        )
-                        .await?;
-                    let mut vec: Vec<String> = vec![];
-                    while let Some(item) = iter.next_item().await {
-                        vec.push(item);
-                        if vec.len() >= count {
-                            break;
-                        }
+        let pattern = pattern.into();
+        let mut cursor = ScanCursor::default();
+        let mut keys = vec![];
+        while keys.len() < count && !cursor.is_finished() {
+            keys.extend(self.scan_batch(pattern.clone(), count, &mut cursor).await?);
+        }
+        Ok(keys)
+    }
+
+    /// Keys of the selected database summed over the masters.
+    pub async fn dbsize(&self) -> Result<u64> {
+        let replies = self.node_cmd::<u64>(&cmd("DBSIZE"), true).await?;
+        Ok(replies.iter().map(|(_, size)| size).sum())
+    }
+
+    /// Type, memory, length and TTL of a key, for the keyspace analyzer.
+    pub async fn key_stats(&self, key: &str) -> Result<KeyStats> {
+        let key_type = self.key_type(key).await?;
+        let length = async {
+            let length = match key_type.as_str() {
+                "string" => self.strlen(key).await?,
+                "list" => self.llen(key).await?,
+                "set" => self.scard(key).await?,
+                "zset" => self.zcard(key).await?,
+                "hash" => self.hlen(key).await?,
+                "stream" => self.xlen(key).await?,
+                _ => 0,
+            };
+            Ok::<usize, Error>(length)
+        };
+        let (memory, ttl, length) = futures::try_join!(self.mem_usage(key), self.ttl(key), length)?;
+        Ok(KeyStats {
+            key: key.to_string(),
+            key_type,
+            memory: memory.max(0) as u64,
+            length: length as u64,
+            ttl,
+        })
+    }
+
+    /// [`RedisOperations::key_stats`] of many keys, `concurrency` at a time.
+    /// Keys that fail or are gone since they were scanned are skipped.
+    pub async fn keys_stats(&self, keys: &[String], concurrency: usize) -> Vec<KeyStats> {
+        let mut stats = vec![];
+        for chunk in keys.chunks(concurrency.max(1)) {
+            let results = join_all(chunk.iter().map(|key| self.key_stats(key))).await;
+            stats.extend(results.into_iter().flatten().filter(|stats| stats.key_type != "none"));
+        }
+        stats
+    }
+
+    /// Issue one `SCAN` on every node that is not exhausted yet, resuming from `cursor`.
+    pub async fn scan_batch(
+        &self,
+        pattern: impl Into<String>,
+        count: usize,
+        cursor: &mut ScanCursor,
+    ) -> Result<Vec<String>> {
+        let pattern = pattern.into();
+        if !cursor.started {
+            if self.is_cluster() {
+                for (id, v) in &self.nodes {
+                    if v.is_master {
+                        cursor.positions.insert(id.clone(), Some(0));
                     }
-                    all_node_keys.extend(vec);
                 }
+            } else {
+                cursor.positions.insert(String::new(), Some(0));
             }
-            Ok(all_node_keys)
-        } else {
-            let mut connection = self.get_standalone_connection().await?;
-            let mut iter: AsyncIter<String> = connection
-                .scan_options(
-                    ScanOptions::default()
-                        .with_pattern(pattern)
-                        .with_count(count),
-                )
+            cursor.started = true;
+        }
+        let mut keys = vec![];
+        // positions only move once every node answered, a failed batch is retried as a whole
+        let mut positions = cursor.positions.clone();
+        for (id, position) in positions.iter_mut() {
+            let Some(current) = *position else {
+                continue;
+            };
+            let mut connection = if self.is_cluster() {
+                let node = self.nodes.get(id).context(format!("node {id} not found"))?;
+                IConnection(node.pool.get().await?)
+            } else {
+                self.get_standalone_connection().await?
+            };
+            let (next, batch): (u64, Vec<String>) = cmd("SCAN")
+                .arg(current)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(count)
+                .query_async(&mut connection)
                 .await?;
-            let mut vec: Vec<String> = vec![];
-            while let Some(item) = iter.next_item().await {
-                vec.push(item);
-                if vec.len() >= count {
-                    break;
-                }
-            }
-            Ok(vec)
+            *position = if next == 0 { None } else { Some(next) };
+            keys.extend(batch);
         }
+        cursor.positions = positions;
+        Ok(keys)
     }
 
     pub async fn get<K: ToRedisArgs + Send + Sync, V: FromRedisValue>(&self, key: K) -> Result<V> {
@@ -1449,14 +2133,219 @@ impl Disposable for DisposableMonitor {
     }
 }
 
-struct IClusterConnection(deadpool_redis::cluster::Connection);
+/// Resumable position of a key scan, one cursor per master node.
+#[derive(Clone, Debug, Default)]
+pub struct ScanCursor {
+    started: bool,
+    /// Node id (empty for standalone) to the next cursor, `None` once the node is exhausted.
+    positions: BTreeMap<String, Option<u64>>,
+}
+
+impl ScanCursor {
+    pub fn is_finished(&self) -> bool {
+        self.started && self.positions.values().all(Option::is_none)
+    }
+
+    /// Exhausted nodes and total nodes.
+    pub fn progress(&self) -> (usize, usize) {
+        let finished = self.positions.values().filter(|p| p.is_none()).count();
+        (finished, self.positions.len())
+    }
+}
+
+/// A server answering [`RedisOperations::node_cmd`], `id` is empty in standalone mode.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Node {
+    pub id: String,
+    pub addr: String,
+    pub is_master: bool,
+}
+
+/// Statistics of one logical database from `INFO keyspace`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyspace {
+    pub db: u32,
+    pub keys: u64,
+    /// Keys with an expiration.
+    pub expires: u64,
+    /// Average ttl of the expiring keys in milliseconds.
+    pub avg_ttl: u64,
+}
+
+impl Keyspace {
+    /// Parse lines like `db0:keys=1,expires=0,avg_ttl=0`, unknown fields are ignored.
+    pub fn parse(info: &str) -> Vec<Keyspace> {
+        let mut keyspaces = vec![];
+        for line in info.lines() {
+            let Some((db, fields)) = line.trim().split_once(':') else {
+                continue;
+            };
+            let Some(Ok(db)) = db.strip_prefix("db").map(str::parse::<u32>) else {
+                continue;
+            };
+            let mut keyspace = Keyspace { db, ..Default::default() };
+            for field in fields.split(',') {
+                if let Some((k, v)) = field.split_once('=') {
+                    let v = v.parse::<u64>().unwrap_or(0);
+                    match k {
+                        "keys" => keyspace.keys = v,
+                        "expires" => keyspace.expires = v,
+                        "avg_ttl" => keyspace.avg_ttl = v,
+                        _ => {}
+                    }
+                }
+            }
+            keyspaces.push(keyspace);
+        }
+        keyspaces
+    }
+}
+
+/// A library from `FUNCTION LIST WITHCODE`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctionLibrary {
+    pub name: String,
+    pub engine: String,
+    pub functions: Vec<Function>,
+    pub code: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub description: Option<String>,
+    /// e.g. `no-writes`, such functions may be called with `FCALL_RO`.
+    pub flags: Vec<String>,
+}
+
+impl FunctionLibrary {
+    /// Parse the reply of `FUNCTION LIST`, libraries are flat arrays in RESP2 and maps in RESP3.
+    pub fn parse(value: &Value) -> Vec<FunctionLibrary> {
+        let Value::Array(libraries) = value else {
+            return vec![];
+        };
+        libraries
+            .iter()
+            .map(|library| {
+                let mut function_library = FunctionLibrary::default();
+                for (k, v) in reply_entries(library) {
+                    match reply_string(k).as_str() {
+                        "library_name" => function_library.name = reply_string(v),
+                        "engine" => function_library.engine = reply_string(v),
+                        "library_code" => function_library.code = reply_string(v),
+                        "functions" => {
+                            if let Value::Array(functions) = v {
+                                function_library.functions = functions.iter().map(Function::parse).collect();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                function_library
+            })
+            .collect()
+    }
+}
+
+impl Function {
+    fn parse(value: &Value) -> Function {
+        let mut function = Function::default();
+        for (k, v) in reply_entries(value) {
+            match reply_string(k).as_str() {
+                "name" => function.name = reply_string(v),
+                "description" => {
+                    if v != &Value::Nil {
+                        function.description = Some(reply_string(v));
+                    }
+                }
+                "flags" => {
+                    if let Value::Array(flags) | Value::Set(flags) = v {
+                        function.flags = flags.iter().map(reply_string).collect();
+                    }
+                }
+                _ => {}
+            }
+        }
+        function
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.flags.iter().any(|flag| flag == "no-writes")
+    }
+}
+
+/// Replies of [`RedisOperations::node_cmd_each`], or an error naming every node that failed.
+fn per_node_result<V>(replies: Vec<(Node, Result<V>)>) -> Result<Vec<V>> {
+    let total = replies.len();
+    let mut values = vec![];
+    let mut errors = vec![];
+    for (node, reply) in replies {
+        match reply {
+            Ok(value) => values.push(value),
+            Err(e) if total == 1 => return Err(e),
+            Err(e) => errors.push(format!("{}: {}", node.addr, e)),
+        }
+    }
+    if !errors.is_empty() {
+        bail!("failed on {} of {} nodes, {}", errors.len(), total, errors.join("; "));
+    }
+    Ok(values)
+}
+
+/// Key/value pairs of a map reply, or of a flat `[k1, v1, k2, v2]` array.
+fn reply_entries(value: &Value) -> Vec<(&Value, &Value)> {
+    match value {
+        Value::Map(map) => map.iter().map(|(k, v)| (k, v)).collect(),
+        Value::Array(array) => array
+            .chunks_exact(2)
+            .filter_map(|pair| match pair {
+                [k, v] => Some((k, v)),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn reply_string(value: &Value) -> String {
+    String::from_redis_value(value).unwrap_or_default()
+}
+
+enum IClusterConnection {
+    Pooled(deadpool_redis::cluster::Connection),
+    Tunneled(ClusterConnection),
+}
+
+/// Resolves cluster node addresses to their local ssh tunnel, so that
+/// redirections to private addresses still go through the tunnel.
+struct TunnelResolver(HashMap<(String, u16), SocketAddr>);
+
+impl AsyncDNSResolver for TunnelResolver {
+    fn resolve<'a, 'b: 'a>(
+        &'a self,
+        host: &'b str,
+        port: u16,
+    ) -> RedisFuture<'a, Box<dyn Iterator<Item = SocketAddr> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(addr) = self.0.get(&(host.to_string(), port)) {
+                return Ok(Box::new(std::iter::once(*addr)) as Box<dyn Iterator<Item = SocketAddr> + Send>);
+            }
+            warn!("Cluster node {host}:{port} has no ssh tunnel, connecting directly");
+            let addrs = tokio::net::lookup_host((host, port)).await?;
+            Ok(Box::new(addrs) as Box<dyn Iterator<Item = SocketAddr> + Send>)
+        })
+    }
+}
 
 struct IConnection(deadpool_redis::Connection);
 
 impl deadpool_redis::redis::aio::ConnectionLike for IClusterConnection {
     fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
         log_cmd(cmd);
-        self.0.req_packed_command(cmd)
+        match self {
+            IClusterConnection::Pooled(connection) => connection.req_packed_command(cmd),
+            IClusterConnection::Tunneled(connection) => connection.req_packed_command(cmd),
+        }
     }
 
     fn req_packed_commands<'a>(
@@ -1465,11 +2354,18 @@ impl deadpool_redis::redis::aio::ConnectionLike for IClusterConnection {
         offset: usize,
         count: usize,
     ) -> RedisFuture<'a, Vec<Value>> {
-        self.0.req_packed_commands(cmd, offset, count)
+        cmd.cmd_iter().for_each(log_cmd);
+        match self {
+            IClusterConnection::Pooled(connection) => connection.req_packed_commands(cmd, offset, count),
+            IClusterConnection::Tunneled(connection) => connection.req_packed_commands(cmd, offset, count),
+        }
     }
 
     fn get_db(&self) -> i64 {
-        self.0.get_db()
+        match self {
+            IClusterConnection::Pooled(connection) => connection.get_db(),
+            IClusterConnection::Tunneled(connection) => connection.get_db(),
+        }
     }
 }
 
@@ -1485,6 +2381,7 @@ impl deadpool_redis::redis::aio::ConnectionLike for IConnection {
         offset: usize,
         count: usize,
     ) -> RedisFuture<'a, Vec<Value>> {
+        cmd.cmd_iter().for_each(log_cmd);
         self.0.req_packed_commands(cmd, offset, count)
     }
 
@@ -1492,3 +2389,54 @@ impl deadpool_redis::redis::aio::ConnectionLike for IConnection {
         self.0.get_db()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::redis_opt::{FunctionLibrary, Keyspace};
+    use deadpool_redis::redis::Value;
+
+    #[test]
+    fn test_parse_keyspace() {
+        let info = "# Keyspace\r\ndb0:keys=12,expires=3,avg_ttl=5000,subexpiry=0\r\ndb3:keys=1,expires=0,avg_ttl=0\r\n";
+        assert_eq!(
+            Keyspace::parse(info),
+            vec![
+                Keyspace { db: 0, keys: 12, expires: 3, avg_ttl: 5000 },
+                Keyspace { db: 3, keys: 1, expires: 0, avg_ttl: 0 },
+            ]
+        );
+        assert!(Keyspace::parse("# Keyspace\r\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_function_list() {
+        let bulk = |s: &str| Value::BulkString(s.as_bytes().to_vec());
+        let resp2 = Value::Array(vec![Value::Array(vec![
+            bulk("library_name"),
+            bulk("mylib"),
+            bulk("engine"),
+            bulk("LUA"),
+            bulk("functions"),
+            Value::Array(vec![Value::Array(vec![
+                bulk("name"),
+                bulk("myfunc"),
+                bulk("description"),
+                Value::Nil,
+                bulk("flags"),
+                Value::Array(vec![bulk("no-writes")]),
+            ])]),
+            bulk("library_code"),
+            bulk("#!lua name=mylib"),
+        ])]);
+        let libraries = FunctionLibrary::parse(&resp2);
+        assert_eq!(libraries.len(), 1);
+        let library = libraries.first();
+        assert_eq!(library.map(|l| l.name.as_str()), Some("mylib"));
+        assert_eq!(library.map(|l| l.code.as_str()), Some("#!lua name=mylib"));
+        let function = library.and_then(|l| l.functions.first());
+        assert_eq!(function.map(|f| f.name.as_str()), Some("myfunc"));
+        assert_eq!(function.and_then(|f| f.description.clone()), None);
+        assert!(function.is_some_and(|f| f.is_read_only()));
+        assert!(FunctionLibrary::parse(&Value::Nil).is_empty());
+    }
+}
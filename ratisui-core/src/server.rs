@@ -0,0 +1,722 @@
+use crate::redis_opt::Node;
+use chrono::{DateTime, Local};
+use deadpool_redis::redis::{FromRedisValue, Value};
+use std::collections::HashMap;
+use std::ops::Add;
+
+/// Reply of `INFO`, sections are kept in the order the server sends them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Info {
+    pub sections: Vec<InfoSection>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InfoSection {
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl Info {
+    /// Parse `# Section` headers followed by `key:value` lines.
+    pub fn parse(info: &str) -> Info {
+        let mut sections: Vec<InfoSection> = vec![];
+        for line in info.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('#') {
+                sections.push(InfoSection {
+                    name: name.trim().to_string(),
+                    fields: vec![],
+                });
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            if sections.is_empty() {
+                sections.push(InfoSection::default());
+            }
+            if let Some(section) = sections.last_mut() {
+                section.fields.push((key.to_string(), value.to_string()));
+            }
+        }
+        Info { sections }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.fields.iter())
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn get_u64(&self, key: &str) -> u64 {
+        self.get(key).and_then(|v| v.parse().ok()).unwrap_or(0)
+    }
+
+    pub fn get_f64(&self, key: &str) -> f64 {
+        self.get(key).and_then(|v| v.parse().ok()).unwrap_or(0.0)
+    }
+}
+
+/// Counters charted by the dashboard, summed over the masters of a cluster.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Metrics {
+    pub ops_per_sec: u64,
+    pub used_memory: u64,
+    pub connected_clients: u64,
+    pub keyspace_hits: u64,
+    pub keyspace_misses: u64,
+    pub evicted_keys: u64,
+    pub input_kbps: f64,
+    pub output_kbps: f64,
+}
+
+impl Metrics {
+    pub fn from_info(info: &Info) -> Metrics {
+        Metrics {
+            ops_per_sec: info.get_u64("instantaneous_ops_per_sec"),
+            used_memory: info.get_u64("used_memory"),
+            connected_clients: info.get_u64("connected_clients"),
+            keyspace_hits: info.get_u64("keyspace_hits"),
+            keyspace_misses: info.get_u64("keyspace_misses"),
+            evicted_keys: info.get_u64("evicted_keys"),
+            input_kbps: info.get_f64("instantaneous_input_kbps"),
+            output_kbps: info.get_f64("instantaneous_output_kbps"),
+        }
+    }
+
+    /// Hit ratio in percent of the lookups made since `previous`, or since startup without it.
+    /// `None` when there was no lookup.
+    pub fn hit_ratio(&self, previous: Option<&Metrics>) -> Option<f64> {
+        let (hits, misses) = match previous {
+            Some(previous) => (
+                self.keyspace_hits.saturating_sub(previous.keyspace_hits),
+                self.keyspace_misses.saturating_sub(previous.keyspace_misses),
+            ),
+            None => (self.keyspace_hits, self.keyspace_misses),
+        };
+        let lookups = hits + misses;
+        (lookups > 0).then(|| hits as f64 * 100.0 / lookups as f64)
+    }
+
+    /// Keys evicted since `previous`, counters restart from zero after a server restart.
+    pub fn evictions_since(&self, previous: Option<&Metrics>) -> u64 {
+        previous.map_or(0, |previous| self.evicted_keys.saturating_sub(previous.evicted_keys))
+    }
+}
+
+impl Add for Metrics {
+    type Output = Metrics;
+
+    fn add(self, rhs: Metrics) -> Metrics {
+        Metrics {
+            ops_per_sec: self.ops_per_sec + rhs.ops_per_sec,
+            used_memory: self.used_memory + rhs.used_memory,
+            connected_clients: self.connected_clients + rhs.connected_clients,
+            keyspace_hits: self.keyspace_hits + rhs.keyspace_hits,
+            keyspace_misses: self.keyspace_misses + rhs.keyspace_misses,
+            evicted_keys: self.evicted_keys + rhs.evicted_keys,
+            input_kbps: self.input_kbps + rhs.input_kbps,
+            output_kbps: self.output_kbps + rhs.output_kbps,
+        }
+    }
+}
+
+/// An entry of `SLOWLOG GET`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlowlogEntry {
+    /// Address of the node that logged the command.
+    pub node: String,
+    pub id: u64,
+    /// Unix time in seconds.
+    pub timestamp: i64,
+    /// Execution time in microseconds.
+    pub duration: u64,
+    pub args: Vec<String>,
+    pub client_addr: String,
+    pub client_name: String,
+}
+
+impl SlowlogEntry {
+    /// Parse `[id, timestamp, duration, [arg ...], client addr, client name]` entries,
+    /// the client fields are missing before redis 4.0.
+    pub fn parse(node: &str, value: &Value) -> Vec<SlowlogEntry> {
+        let Value::Array(entries) = value else {
+            return vec![];
+        };
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let Value::Array(fields) = entry else {
+                    return None;
+                };
+                let mut fields = fields.iter();
+                let id = u64::from_redis_value(fields.next()?).ok()?;
+                let timestamp = i64::from_redis_value(fields.next()?).ok()?;
+                let duration = u64::from_redis_value(fields.next()?).ok()?;
+                let args = Vec::<String>::from_redis_value(fields.next()?).unwrap_or_default();
+                let mut next_string = || {
+                    fields
+                        .next()
+                        .and_then(|field| String::from_redis_value(field).ok())
+                        .unwrap_or_default()
+                };
+                Some(SlowlogEntry {
+                    node: node.to_string(),
+                    id,
+                    timestamp,
+                    duration,
+                    args,
+                    client_addr: next_string(),
+                    client_name: next_string(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn time(&self) -> String {
+        format_unix_time(self.timestamp)
+    }
+
+    pub fn command_line(&self) -> String {
+        self.args.join(" ")
+    }
+}
+
+/// A connection of `CLIENT LIST`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// The node the client is connected to.
+    pub node: Node,
+    pub id: u64,
+    pub addr: String,
+    pub name: String,
+    /// Connection age in seconds.
+    pub age: u64,
+    /// Idle time in seconds.
+    pub idle: u64,
+    pub db: u32,
+    pub flags: String,
+    /// Last command run by the client.
+    pub cmd: String,
+    /// Total memory used by the client in bytes, `tot-mem` is only reported since redis 6.0.
+    pub memory: u64,
+}
+
+impl ClientInfo {
+    /// Parse one `key=value ...` line per client, unknown fields are ignored.
+    pub fn parse(node: &Node, list: &str) -> Vec<ClientInfo> {
+        list.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .filter_map(|line| {
+                let mut client = ClientInfo {
+                    node: node.clone(),
+                    ..Default::default()
+                };
+                for (key, value) in line.split(' ').filter_map(|field| field.split_once('=')) {
+                    match key {
+                        "id" => client.id = value.parse().ok()?,
+                        "addr" => client.addr = value.to_string(),
+                        "name" => client.name = value.to_string(),
+                        "age" => client.age = value.parse().unwrap_or(0),
+                        "idle" => client.idle = value.parse().unwrap_or(0),
+                        "db" => client.db = value.parse().unwrap_or(0),
+                        "flags" => client.flags = value.to_string(),
+                        "cmd" => client.cmd = value.to_string(),
+                        "tot-mem" => client.memory = value.parse().unwrap_or(0),
+                        _ => {}
+                    }
+                }
+                Some(client)
+            })
+            .collect()
+    }
+
+    /// Case-insensitive match on the addr, name, flags and command.
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        [&self.addr, &self.name, &self.flags, &self.cmd, &self.node.addr]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&filter))
+    }
+}
+
+/// An event of `LATENCY LATEST`, latencies are in milliseconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyEvent {
+    /// Master that reported the event, its history has to be read there.
+    pub node: Node,
+    pub event: String,
+    /// Unix time in seconds of the latest spike.
+    pub timestamp: i64,
+    pub latest: u64,
+    pub max: u64,
+}
+
+impl LatencyEvent {
+    /// Parse `[event, timestamp, latest, max]` entries.
+    pub fn parse(node: &Node, value: &Value) -> Vec<LatencyEvent> {
+        let Value::Array(entries) = value else {
+            return vec![];
+        };
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let Value::Array(fields) = entry else {
+                    return None;
+                };
+                let mut fields = fields.iter();
+                Some(LatencyEvent {
+                    node: node.clone(),
+                    event: String::from_redis_value(fields.next()?).ok()?,
+                    timestamp: i64::from_redis_value(fields.next()?).ok()?,
+                    latest: u64::from_redis_value(fields.next()?).ok()?,
+                    max: u64::from_redis_value(fields.next()?).ok()?,
+                })
+            })
+            .collect()
+    }
+
+    pub fn time(&self) -> String {
+        format_unix_time(self.timestamp)
+    }
+}
+
+/// Parse the `[timestamp, latency]` samples of `LATENCY HISTORY`, oldest first.
+pub fn parse_latency_history(value: &Value) -> Vec<(i64, u64)> {
+    let mut samples = Vec::<(i64, u64)>::from_redis_value(value).unwrap_or_default();
+    samples.sort_by_key(|(timestamp, _)| *timestamp);
+    samples
+}
+
+/// A keyspace notification, from `__keyevent@<db>__:<event>` or `__keyspace@<db>__:<key>`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyNotification {
+    pub key: String,
+    /// e.g. `set`, `del`, `expired`.
+    pub event: String,
+}
+
+impl KeyNotification {
+    /// Parse a `{channel: payload}` message of [`crate::redis_opt::RedisOperations::psubscribe`].
+    pub fn parse(value: &Value) -> Option<KeyNotification> {
+        let Value::Map(entries) = value else {
+            return None;
+        };
+        let (channel, payload) = entries.first()?;
+        let channel = String::from_redis_value(channel).ok()?;
+        let payload = String::from_redis_value(payload).ok()?;
+        let (kind, rest) = channel.split_once("__:")?;
+        if kind.starts_with("__keyevent@") {
+            Some(KeyNotification { key: payload, event: rest.to_string() })
+        } else if kind.starts_with("__keyspace@") {
+            Some(KeyNotification { key: rest.to_string(), event: payload })
+        } else {
+            None
+        }
+    }
+
+    /// The key is gone from the database after this event.
+    pub fn is_removal(&self) -> bool {
+        matches!(self.event.as_str(), "del" | "expired" | "evicted" | "rename_from" | "move_from")
+    }
+}
+
+/// `notify-keyspace-events` flags that publish every keyevent notification,
+/// keeping the current ones, `None` when `current` already does.
+pub fn keyevent_flags(current: &str) -> Option<String> {
+    let all_classes = current.contains('A') || "g$lshzxetd".chars().all(|class| current.contains(class));
+    if current.contains('E') && all_classes {
+        return None;
+    }
+    let mut flags = current.to_string();
+    if !current.contains('E') {
+        flags.push('E');
+    }
+    if !all_classes {
+        flags.push('A');
+    }
+    Some(flags)
+}
+
+/// Redis release [`CONFIG_DEFAULTS`] were taken from, other versions have no default column.
+pub const CONFIG_DEFAULTS_VERSION: &str = "7.2";
+
+/// Parameters `CONFIG SET` refuses on redis [`CONFIG_DEFAULTS_VERSION`], they can only be changed in the config file.
+static IMMUTABLE_CONFIGS: &[&str] = &[
+    "aclfile",
+    "always-show-logo",
+    "appenddirname",
+    "appendfilename",
+    "cluster-config-file",
+    "cluster-enabled",
+    "cluster-port",
+    "daemonize",
+    "databases",
+    "disable-thp",
+    "enable-debug-command",
+    "enable-module-command",
+    "enable-protected-configs",
+    "ignore-warnings",
+    "io-threads",
+    "io-threads-do-reads",
+    "logfile",
+    "pidfile",
+    "rdbchecksum",
+    "set-proc-title",
+    "supervised",
+    "syslog-enabled",
+    "syslog-facility",
+    "syslog-ident",
+    "tcp-backlog",
+    "unixsocket",
+    "unixsocketperm",
+];
+
+/// Built-in defaults of redis [`CONFIG_DEFAULTS_VERSION`] as `CONFIG GET` reports them.
+static CONFIG_DEFAULTS: &[(&str, &str)] = &[
+    ("active-expire-effort", "1"),
+    ("activedefrag", "no"),
+    ("activerehashing", "yes"),
+    ("acllog-max-len", "128"),
+    ("always-show-logo", "no"),
+    ("aof-load-truncated", "yes"),
+    ("aof-rewrite-incremental-fsync", "yes"),
+    ("aof-timestamp-enabled", "no"),
+    ("aof-use-rdb-preamble", "yes"),
+    ("appenddirname", "appendonlydir"),
+    ("appendfilename", "appendonly.aof"),
+    ("appendfsync", "everysec"),
+    ("appendonly", "no"),
+    ("auto-aof-rewrite-min-size", "67108864"),
+    ("auto-aof-rewrite-percentage", "100"),
+    ("bind", "* -::*"),
+    ("busy-reply-threshold", "5000"),
+    ("client-output-buffer-limit", "normal 0 0 0 slave 268435456 67108864 60 pubsub 33554432 8388608 60"),
+    ("client-query-buffer-limit", "1073741824"),
+    ("cluster-allow-reads-when-down", "no"),
+    ("cluster-config-file", "nodes.conf"),
+    ("cluster-enabled", "no"),
+    ("cluster-node-timeout", "15000"),
+    ("cluster-port", "0"),
+    ("cluster-require-full-coverage", "yes"),
+    ("crash-log-enabled", "yes"),
+    ("crash-memcheck-enabled", "yes"),
+    ("daemonize", "no"),
+    ("databases", "16"),
+    ("dbfilename", "dump.rdb"),
+    ("dynamic-hz", "yes"),
+    ("hash-max-listpack-entries", "128"),
+    ("hash-max-listpack-value", "64"),
+    ("hll-sparse-max-bytes", "3000"),
+    ("hz", "10"),
+    ("io-threads", "1"),
+    ("io-threads-do-reads", "no"),
+    ("latency-monitor-threshold", "0"),
+    ("latency-tracking", "yes"),
+    ("lazyfree-lazy-eviction", "no"),
+    ("lazyfree-lazy-expire", "no"),
+    ("lazyfree-lazy-server-del", "no"),
+    ("lazyfree-lazy-user-del", "no"),
+    ("lazyfree-lazy-user-flush", "no"),
+    ("lfu-decay-time", "1"),
+    ("lfu-log-factor", "10"),
+    ("list-compress-depth", "0"),
+    ("list-max-listpack-size", "-2"),
+    ("logfile", ""),
+    ("loglevel", "notice"),
+    ("maxclients", "10000"),
+    ("maxmemory", "0"),
+    ("maxmemory-clients", "0"),
+    ("maxmemory-eviction-tenacity", "10"),
+    ("maxmemory-policy", "noeviction"),
+    ("maxmemory-samples", "5"),
+    ("min-replicas-max-lag", "10"),
+    ("min-replicas-to-write", "0"),
+    ("notify-keyspace-events", ""),
+    ("pidfile", ""),
+    ("port", "6379"),
+    ("protected-mode", "yes"),
+    ("proto-max-bulk-len", "536870912"),
+    ("rdb-del-sync-files", "no"),
+    ("rdb-save-incremental-fsync", "yes"),
+    ("rdbchecksum", "yes"),
+    ("rdbcompression", "yes"),
+    ("repl-backlog-size", "1048576"),
+    ("repl-backlog-ttl", "3600"),
+    ("repl-diskless-load", "disabled"),
+    ("repl-diskless-sync", "yes"),
+    ("repl-diskless-sync-delay", "5"),
+    ("repl-ping-replica-period", "10"),
+    ("repl-timeout", "60"),
+    ("replica-lazy-flush", "no"),
+    ("replica-priority", "100"),
+    ("replica-read-only", "yes"),
+    ("replica-serve-stale-data", "yes"),
+    ("save", "3600 1 300 100 60 10000"),
+    ("set-max-intset-entries", "512"),
+    ("set-max-listpack-entries", "128"),
+    ("set-max-listpack-value", "64"),
+    ("shutdown-timeout", "10"),
+    ("slowlog-log-slower-than", "10000"),
+    ("slowlog-max-len", "128"),
+    ("stop-writes-on-bgsave-error", "yes"),
+    ("stream-node-max-bytes", "4096"),
+    ("stream-node-max-entries", "100"),
+    ("supervised", "no"),
+    ("tcp-backlog", "511"),
+    ("tcp-keepalive", "300"),
+    ("timeout", "0"),
+    ("tracking-table-max-keys", "1000000"),
+    ("zset-max-listpack-entries", "128"),
+    ("zset-max-listpack-value", "64"),
+];
+
+/// A parameter of `CONFIG GET *`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigParam {
+    pub name: String,
+    /// Value of the first node, see `diverges`.
+    pub value: String,
+    /// Default of redis [`CONFIG_DEFAULTS_VERSION`], `None` on other versions and for parameters
+    /// without a known default, e.g. `dir` or module parameters.
+    pub default: Option<&'static str>,
+    /// Not the same value on every node that was read.
+    pub diverges: bool,
+    /// Known to be refused by `CONFIG SET` on redis [`CONFIG_DEFAULTS_VERSION`].
+    pub immutable: bool,
+}
+
+impl ConfigParam {
+    /// Merge the `name => value` replies of `CONFIG GET *` on each node, sorted by name.
+    /// Defaults and immutable flags are only attached when `redis_version` is a [`CONFIG_DEFAULTS_VERSION`] release.
+    pub fn parse(configs: Vec<HashMap<String, String>>, redis_version: Option<&str>) -> Vec<ConfigParam> {
+        let known_defaults = redis_version.is_some_and(|version| {
+            version == CONFIG_DEFAULTS_VERSION || version.starts_with(&format!("{CONFIG_DEFAULTS_VERSION}."))
+        });
+        let mut configs = configs.into_iter();
+        let Some(first) = configs.next() else {
+            return vec![];
+        };
+        let others = configs.collect::<Vec<_>>();
+        let mut params = first
+            .into_iter()
+            .map(|(name, value)| {
+                let default = CONFIG_DEFAULTS
+                    .iter()
+                    .find(|(default_name, _)| *default_name == name)
+                    .map(|(_, default)| *default)
+                    .filter(|_| known_defaults);
+                let diverges = others.iter().any(|config| config.get(&name) != Some(&value));
+                let immutable = known_defaults && IMMUTABLE_CONFIGS.contains(&name.as_str());
+                Self {
+                    name,
+                    value,
+                    default,
+                    diverges,
+                    immutable,
+                }
+            })
+            .collect::<Vec<_>>();
+        params.sort_by(|a, b| a.name.cmp(&b.name));
+        params
+    }
+
+    /// The value differs from the known default.
+    pub fn is_modified(&self) -> bool {
+        self.default.is_some_and(|default| default != self.value)
+    }
+
+    /// Case-insensitive match on the name and the value.
+    pub fn matches(&self, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        self.name.to_lowercase().contains(&filter) || self.value.to_lowercase().contains(&filter)
+    }
+}
+
+/// `CONFIG SET` failed because the parameter can only be set at startup.
+pub fn is_immutable_config_error(message: &str) -> bool {
+    message.contains("immutable config")
+}
+
+/// Local time of a unix timestamp in seconds.
+pub fn format_unix_time(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|time| time.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Microseconds as `850µs`, `12.50ms` or `1.20s`.
+pub fn format_micros(micros: u64) -> String {
+    if micros < 1_000 {
+        format!("{micros}µs")
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", micros as f64 / 1_000_000.0)
+    }
+}
+
+/// Parse a duration like `500`, `500us`, `10ms` or `1.5s` into microseconds, plain numbers are microseconds.
+pub fn parse_micros(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let (number, scale) = if let Some(number) = s.strip_suffix("ms") {
+        (number, 1_000.0)
+    } else if let Some(number) = s.strip_suffix("us").or_else(|| s.strip_suffix("µs")) {
+        (number, 1.0)
+    } else if let Some(number) = s.strip_suffix('s') {
+        (number, 1_000_000.0)
+    } else {
+        (s, 1.0)
+    };
+    let number = number.trim().parse::<f64>().ok()?;
+    (number >= 0.0).then(|| (number * scale) as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::redis_opt::Node;
+    use crate::server::{
+        format_micros, is_immutable_config_error, keyevent_flags, parse_latency_history, parse_micros, ClientInfo,
+        ConfigParam, Info, KeyNotification, LatencyEvent, Metrics, SlowlogEntry,
+    };
+    use deadpool_redis::redis::Value;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_info() {
+        let info = Info::parse(
+            "# Server\r\nredis_version:7.2.4\r\n\r\n# Stats\r\ninstantaneous_ops_per_sec:12\r\nkeyspace_hits:30\r\nkeyspace_misses:10\r\n",
+        );
+        assert_eq!(info.sections.len(), 2);
+        assert_eq!(info.get("redis_version"), Some("7.2.4"));
+        assert_eq!(info.get_u64("instantaneous_ops_per_sec"), 12);
+        assert_eq!(info.get_u64("unknown"), 0);
+
+        let metrics = Metrics::from_info(&info);
+        assert_eq!(metrics.hit_ratio(None), Some(75.0));
+        let previous = Metrics { keyspace_hits: 30, keyspace_misses: 10, ..Default::default() };
+        assert_eq!(metrics.hit_ratio(Some(&previous)), None);
+    }
+
+    #[test]
+    fn test_parse_slowlog() {
+        let bulk = |s: &str| Value::BulkString(s.as_bytes().to_vec());
+        let reply = Value::Array(vec![
+            Value::Array(vec![
+                Value::Int(14),
+                Value::Int(1_700_000_000),
+                Value::Int(12_500),
+                Value::Array(vec![bulk("KEYS"), bulk("*")]),
+                bulk("127.0.0.1:58217"),
+                bulk("worker"),
+            ]),
+            Value::Array(vec![Value::Int(13), Value::Int(1_700_000_000), Value::Int(10), Value::Array(vec![])]),
+        ]);
+        let entries = SlowlogEntry::parse("127.0.0.1:6379", &reply);
+        assert_eq!(entries.len(), 2);
+        let first = entries.first();
+        assert_eq!(first.map(SlowlogEntry::command_line).as_deref(), Some("KEYS *"));
+        assert_eq!(first.map(|entry| entry.client_name.as_str()), Some("worker"));
+        assert_eq!(entries.get(1).map(|entry| entry.client_addr.as_str()), Some(""));
+    }
+
+    #[test]
+    fn test_parse_client_list() {
+        let list = "id=3 addr=127.0.0.1:56780 laddr=127.0.0.1:6379 fd=8 name=worker age=120 idle=2 flags=N db=1 cmd=client|list tot-mem=22400\n\
+                    id=4 addr=10.0.0.2:41022 fd=9 name= age=5 idle=5 flags=S db=0 cmd=replconf\n";
+        let clients = ClientInfo::parse(&Node::default(), list);
+        assert_eq!(clients.len(), 2);
+        let first = clients.first();
+        assert_eq!(first.map(|client| (client.id, client.db, client.memory)), Some((3, 1, 22400)));
+        assert_eq!(first.map(|client| client.cmd.as_str()), Some("client|list"));
+        assert!(first.is_some_and(|client| client.matches("WORKER")));
+        assert_eq!(clients.get(1).map(|client| client.name.as_str()), Some(""));
+        assert!(clients.get(1).is_some_and(|client| !client.matches("worker")));
+    }
+
+    #[test]
+    fn test_parse_latency() {
+        let reply = Value::Array(vec![Value::Array(vec![
+            Value::BulkString(b"command".to_vec()),
+            Value::Int(1_700_000_010),
+            Value::Int(120),
+            Value::Int(350),
+        ])]);
+        let node = Node {
+            addr: "127.0.0.1:7000".to_string(),
+            ..Default::default()
+        };
+        let events = LatencyEvent::parse(&node, &reply);
+        assert_eq!(events.first().map(|event| (event.event.as_str(), event.latest, event.max)), Some(("command", 120, 350)));
+        assert!(events.first().is_some_and(|event| event.node == node));
+
+        let history = Value::Array(vec![
+            Value::Array(vec![Value::Int(1_700_000_010), Value::Int(120)]),
+            Value::Array(vec![Value::Int(1_700_000_000), Value::Int(350)]),
+        ]);
+        assert_eq!(parse_latency_history(&history), vec![(1_700_000_000, 350), (1_700_000_010, 120)]);
+    }
+
+    #[test]
+    fn test_key_notification() {
+        let message = |channel: &str, payload: &str| {
+            Value::Map(vec![(
+                Value::SimpleString(channel.to_string()),
+                Value::BulkString(payload.as_bytes().to_vec()),
+            )])
+        };
+        let notification = KeyNotification::parse(&message("__keyevent@0__:expired", "session:1"));
+        assert_eq!(notification.as_ref().map(|n| n.key.as_str()), Some("session:1"));
+        assert!(notification.is_some_and(|n| n.is_removal()));
+        let notification = KeyNotification::parse(&message("__keyspace@0__:user:1", "hset"));
+        assert_eq!(notification.as_ref().map(|n| (n.key.as_str(), n.event.as_str())), Some(("user:1", "hset")));
+        assert!(notification.is_some_and(|n| !n.is_removal()));
+        assert_eq!(KeyNotification::parse(&message("news", "hello")), None);
+
+        assert_eq!(keyevent_flags("").as_deref(), Some("EA"));
+        assert_eq!(keyevent_flags("Ex").as_deref(), Some("ExA"));
+        assert_eq!(keyevent_flags("KEA"), None);
+    }
+
+    #[test]
+    fn test_config_params() {
+        let config = HashMap::from([
+            ("maxmemory".to_string(), "1073741824".to_string()),
+            ("databases".to_string(), "16".to_string()),
+            ("dir".to_string(), "/data".to_string()),
+        ]);
+        let mut replica = config.clone();
+        replica.insert("maxmemory".to_string(), "0".to_string());
+        let params = ConfigParam::parse(vec![config.clone(), replica], Some("7.2.4"));
+        let names = params.iter().map(|param| param.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["databases", "dir", "maxmemory"]);
+        let flags = params
+            .iter()
+            .map(|param| (param.immutable, param.is_modified(), param.diverges))
+            .collect::<Vec<_>>();
+        assert_eq!(flags, [(true, false, false), (false, false, false), (false, true, true)]);
+        assert!(params.get(1).is_some_and(|param| param.default.is_none() && param.matches("DATA")));
+        // defaults of another release are unknown
+        let params = ConfigParam::parse(vec![config], Some("7.4.0"));
+        assert!(params.iter().all(|param| param.default.is_none() && !param.is_modified() && !param.immutable));
+        assert!(is_immutable_config_error(
+            "ERR CONFIG SET failed (possibly related to argument 'databases') - can't set immutable config"
+        ));
+    }
+
+    #[test]
+    fn test_micros() {
+        assert_eq!(parse_micros("500"), Some(500));
+        assert_eq!(parse_micros("10ms"), Some(10_000));
+        assert_eq!(parse_micros("1.5s"), Some(1_500_000));
+        assert_eq!(parse_micros("fast"), None);
+        assert_eq!(format_micros(850), "850µs");
+        assert_eq!(format_micros(12_500), "12.50ms");
+    }
+}
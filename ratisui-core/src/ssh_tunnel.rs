@@ -1,11 +1,18 @@
-use anyhow::{Context, Error, Result, bail};
+use crate::configuration::{JumpHost, SshAuth};
+use anyhow::{Context, Error, Result, anyhow, bail};
+use crossbeam_channel::{Receiver, Sender};
 use log::{error, info, warn};
-use russh::client::{AuthResult, Config, Handler};
-use russh::keys::{PrivateKey, PrivateKeyWithHashAlg, PublicKey, load_secret_key};
+use once_cell::sync::Lazy;
+use russh::client::{AuthResult, Config, Handle, Handler};
+#[cfg(unix)]
+use russh::keys::agent::client::AgentClient;
+use russh::keys::{HashAlg, PrivateKey, PrivateKeyWithHashAlg, PublicKey, check_known_hosts_path, learn_known_hosts_path, load_secret_key};
 use russh::Disconnect;
 use std::future::Future;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpListener;
 use tokio::select;
@@ -17,6 +24,9 @@ pub struct SshTunnel {
     pub port: u16,
     pub username: String,
     pub password: String,
+    pub auth: SshAuth,
+    pub known_hosts: Option<String>,
+    pub jump_hosts: Vec<JumpHost>,
     pub forwarding_host: String,
     pub forwarding_port: u16,
     tx: tokio::sync::watch::Sender<u8>,
@@ -39,6 +49,9 @@ impl SshTunnel {
             port,
             username,
             password,
+            auth: SshAuth::default(),
+            known_hosts: None,
+            jump_hosts: vec![],
             forwarding_host,
             forwarding_port,
             tx,
@@ -47,59 +60,87 @@ impl SshTunnel {
         }
     }
 
+    pub fn auth(mut self, auth: SshAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Use another known_hosts file instead of `~/.ssh/known_hosts`.
+    pub fn known_hosts(mut self, known_hosts: Option<String>) -> Self {
+        self.known_hosts = known_hosts;
+        self
+    }
+
+    /// Reach `host` through the given jump hosts, each one is dialed from the previous.
+    pub fn jump_hosts(mut self, jump_hosts: Vec<JumpHost>) -> Self {
+        self.jump_hosts = jump_hosts;
+        self
+    }
+
     pub async fn open(&mut self) -> Result<SocketAddr> {
         if let Some(addr) = self.socket_addr {
             return Ok(addr);
         }
-        let mut ssh_client = russh::client::connect(
-            Arc::new(Config::default()),
-            format!("{}:{}", self.host, self.port),
-            IHandler {},
-        )
-        .await?;
+        let known_hosts = known_hosts_path(self.known_hosts.as_deref())?;
+        let mut hops = self.jump_hosts.clone();
+        hops.push(JumpHost {
+            host: self.host.clone(),
+            port: self.port,
+            username: self.username.clone(),
+            password: self.password.clone(),
+            password_command: None,
+            auth: self.auth.clone(),
+        });
+        // sessions of the jump hosts, must be kept alive as long as the tunnel
+        let mut jump_clients: Vec<Handle<IHandler>> = vec![];
+        let mut last_client: Option<Handle<IHandler>> = None;
+        for hop in &hops {
+            let handler = IHandler {
+                host: hop.host.clone(),
+                port: hop.port,
+                known_hosts: known_hosts.clone(),
+            };
+            let mut client = match last_client.take() {
+                None => russh::client::connect(
+                    Arc::new(Config::default()),
+                    format!("{}:{}", hop.host, hop.port),
+                    handler,
+                )
+                .await?,
+                Some(previous) => {
+                    let channel = previous
+                        .channel_open_direct_tcpip(
+                            hop.host.clone(),
+                            hop.port as u32,
+                            Ipv4Addr::LOCALHOST.to_string(),
+                            0,
+                        )
+                        .await
+                        .context(format!("cannot reach {}:{} through jump host", hop.host, hop.port))?;
+                    jump_clients.push(previous);
+                    russh::client::connect_stream(Arc::new(Config::default()), channel.into_stream(), handler)
+                        .await?
+                }
+            };
+            authenticate(&mut client, hop).await?;
+            last_client = Some(client);
+        }
+        let ssh_client = last_client.context("no ssh host to connect")?;
         let listener = TcpListener::bind(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).await?;
         let addr = listener.local_addr()?;
         let forwarding_host = self.forwarding_host.clone();
         let forwarding_port = self.forwarding_port as u32;
 
-        if self.password.is_empty() {
-            let auth_result = ssh_client
-                .authenticate_publickey(
-                    self.username.clone(),
-                    PrivateKeyWithHashAlg::new(
-                        Arc::new(load_private_key()?),
-                        ssh_client.best_supported_rsa_hash().await?.flatten(),
-                    ),
-                )
-                .await?;
-            if let AuthResult::Failure { .. } = auth_result {
-                bail!("ssh authenticate with public-key failure")
-            }
-            let channel = ssh_client
-                .channel_open_direct_tcpip(
-                    forwarding_host.clone(),
-                    forwarding_port,
-                    Ipv4Addr::LOCALHOST.to_string(),
-                    addr.port() as u32,
-                )
-                .await
-                .context("cannot build ssh tunnel via public-key")?;
-            channel.close().await?;
-        } else {
-            ssh_client
-                .authenticate_password(self.username.clone(), self.password.clone())
-                .await?;
-            let channel = ssh_client
-                .channel_open_direct_tcpip(
-                    forwarding_host.clone(),
-                    forwarding_port,
-                    Ipv4Addr::LOCALHOST.to_string(),
-                    addr.port() as u32,
-                )
-                .await
-                .context("cannot build ssh tunnel via password")?;
-            channel.close().await?;
-        }
+        let channel = ssh_client
+            .channel_open_direct_tcpip(
+                forwarding_host.clone(),
+                forwarding_port,
+                Ipv4Addr::LOCALHOST.to_string(),
+                addr.port() as u32,
+            )
+            .await
+            .context("cannot build ssh tunnel")?;
+        channel.close().await?;
 
         let rx_clone = self.rx.clone();
         tokio::spawn(async move {
@@ -134,6 +175,11 @@ impl SshTunnel {
                     ssh_client
                         .disconnect(Disconnect::ByApplication, "exit", "none")
                         .await?;
+                    for jump_client in jump_clients.iter().rev() {
+                        jump_client
+                            .disconnect(Disconnect::ByApplication, "exit", "none")
+                            .await?;
+                    }
                     break;
                 }
             }
@@ -158,22 +204,187 @@ impl SshTunnel {
     }
 }
 
-fn load_private_key() -> Result<PrivateKey> {
-    let key_path = dirs::home_dir().context("cannot get home directory")?;
-    let key_path = key_path.join(".ssh").join("id_rsa");
-    load_secret_key(key_path, None).context("cannot load secret_key")
+async fn authenticate(ssh_client: &mut Handle<IHandler>, hop: &JumpHost) -> Result<()> {
+    let auth_result = match &hop.auth {
+        SshAuth::Password if !hop.password.is_empty() => ssh_client
+            .authenticate_password(hop.username.clone(), hop.password.clone())
+            .await
+            .context("ssh authenticate with password failure")?,
+        SshAuth::Password => {
+            // no password configured, fall back to the default key pair
+            let key = load_private_key(None, None)?;
+            authenticate_key(ssh_client, &hop.username, key).await?
+        }
+        SshAuth::PrivateKey { path, passphrase } => {
+            let key = load_private_key(Some(path), passphrase.as_deref())?;
+            authenticate_key(ssh_client, &hop.username, key).await?
+        }
+        SshAuth::Agent { socket } => authenticate_agent(ssh_client, &hop.username, socket.as_deref()).await?,
+    };
+    if let AuthResult::Failure { .. } = auth_result {
+        bail!("ssh authenticate failure: {}@{}:{}", hop.username, hop.host, hop.port)
+    }
+    Ok(())
 }
 
-struct IHandler;
+async fn authenticate_key(ssh_client: &mut Handle<IHandler>, username: &str, key: PrivateKey) -> Result<AuthResult> {
+    let hash_alg = ssh_client.best_supported_rsa_hash().await?.flatten();
+    let auth_result = ssh_client
+        .authenticate_publickey(
+            username.to_string(),
+            PrivateKeyWithHashAlg::new(Arc::new(key), hash_alg),
+        )
+        .await
+        .context("ssh authenticate with public-key failure")?;
+    Ok(auth_result)
+}
+
+#[cfg(unix)]
+async fn authenticate_agent(ssh_client: &mut Handle<IHandler>, username: &str, socket: Option<&str>) -> Result<AuthResult> {
+    let mut agent = match socket {
+        Some(socket) if !socket.is_empty() => AgentClient::connect_uds(expand_home(socket)?).await,
+        _ => AgentClient::connect_env().await,
+    }
+    .context("cannot connect to ssh-agent")?;
+    let identities = agent.request_identities().await?;
+    if identities.is_empty() {
+        bail!("ssh-agent has no identities");
+    }
+    let hash_alg = ssh_client.best_supported_rsa_hash().await?.flatten();
+    for identity in identities {
+        let auth_result = ssh_client
+            .authenticate_publickey_with(username.to_string(), identity, hash_alg, &mut agent)
+            .await?;
+        if matches!(auth_result, AuthResult::Success) {
+            return Ok(auth_result);
+        }
+    }
+    bail!("ssh authenticate with agent failure")
+}
+
+#[cfg(not(unix))]
+async fn authenticate_agent(_: &mut Handle<IHandler>, _: &str, _: Option<&str>) -> Result<AuthResult> {
+    bail!("ssh-agent authentication is only supported on unix")
+}
+
+/// Load the given key, or the first of `~/.ssh/id_ed25519`, `~/.ssh/id_rsa` that exists.
+fn load_private_key(path: Option<&str>, passphrase: Option<&str>) -> Result<PrivateKey> {
+    let key_path = match path {
+        Some(path) => expand_home(path)?,
+        None => {
+            let ssh_dir = dirs::home_dir().context("cannot get home directory")?.join(".ssh");
+            ["id_ed25519", "id_rsa"]
+                .iter()
+                .map(|name| ssh_dir.join(name))
+                .find(|path| path.exists())
+                .context("no default key found in ~/.ssh")?
+        }
+    };
+    load_secret_key(&key_path, passphrase)
+        .context(format!("cannot load secret key: {}", key_path.display()))
+}
+
+fn known_hosts_path(path: Option<&str>) -> Result<PathBuf> {
+    match path {
+        Some(path) if !path.is_empty() => expand_home(path),
+        _ => Ok(dirs::home_dir()
+            .context("cannot get home directory")?
+            .join(".ssh")
+            .join("known_hosts")),
+    }
+}
+
+fn expand_home(path: &str) -> Result<PathBuf> {
+    if let Some(rest) = path.strip_prefix("~/") {
+        Ok(dirs::home_dir().context("cannot get home directory")?.join(rest))
+    } else {
+        Ok(PathBuf::from(path))
+    }
+}
+
+/// A server key that is not in known_hosts yet, waiting for the user to trust it or not.
+pub struct HostKeyPrompt {
+    pub host: String,
+    pub port: u16,
+    pub algorithm: String,
+    pub fingerprint: String,
+    reply: tokio::sync::oneshot::Sender<bool>,
+}
+
+impl HostKeyPrompt {
+    pub fn accept(self) {
+        let _ = self.reply.send(true);
+    }
+
+    pub fn reject(self) {
+        let _ = self.reply.send(false);
+    }
+}
+
+/// Unanswered host key prompts reject the key after this long.
+const HOST_KEY_PROMPT_TIMEOUT: Duration = Duration::from_secs(120);
+
+static HOST_KEY_PROMPTS: Lazy<(Sender<HostKeyPrompt>, Receiver<HostKeyPrompt>)> =
+    Lazy::new(|| crossbeam_channel::bounded(4));
+
+pub fn try_recv_host_key_prompt() -> Option<HostKeyPrompt> {
+    HOST_KEY_PROMPTS.1.try_recv().ok()
+}
+
+struct IHandler {
+    host: String,
+    port: u16,
+    known_hosts: PathBuf,
+}
 
 impl Handler for IHandler {
     type Error = Error;
 
     fn check_server_key(
         &mut self,
-        _: &PublicKey,
+        server_public_key: &PublicKey,
     ) -> impl Future<Output = std::result::Result<bool, Self::Error>> + Send {
-        async { Ok(true) }
+        let host = self.host.clone();
+        let port = self.port;
+        let known_hosts = self.known_hosts.clone();
+        let server_public_key = server_public_key.clone();
+        async move {
+            match check_known_hosts_path(&host, port, &server_public_key, &known_hosts) {
+                Ok(true) => Ok(true),
+                Ok(false) => {
+                    // trust on first use, ask the user before remembering the key
+                    let (tx, rx) = tokio::sync::oneshot::channel();
+                    let prompt = HostKeyPrompt {
+                        host: host.clone(),
+                        port,
+                        algorithm: server_public_key.algorithm().to_string(),
+                        fingerprint: server_public_key.fingerprint(HashAlg::Sha256).to_string(),
+                        reply: tx,
+                    };
+                    // never block the runtime on the UI, an undelivered prompt rejects the key
+                    if HOST_KEY_PROMPTS.0.try_send(prompt).is_err() {
+                        bail!("host key of {host}:{port} is unknown and could not be confirmed, rejected");
+                    }
+                    let trusted = match tokio::time::timeout(HOST_KEY_PROMPT_TIMEOUT, rx).await {
+                        Ok(reply) => reply.unwrap_or(false),
+                        Err(_) => bail!("host key of {host}:{port} was not confirmed in time, rejected"),
+                    };
+                    if trusted {
+                        learn_known_hosts_path(&host, port, &server_public_key, &known_hosts)
+                            .context(format!("cannot write {}", known_hosts.display()))?;
+                        info!("Host key of {host}:{port} added to {}", known_hosts.display());
+                        Ok(true)
+                    } else {
+                        bail!("host key of {host}:{port} was not trusted")
+                    }
+                }
+                Err(russh::keys::Error::KeyChanged { line }) => Err(anyhow!(
+                    "host key of {host}:{port} has changed (see {}:{line}), possible man-in-the-middle attack",
+                    known_hosts.display()
+                )),
+                Err(e) => Err(e.into()),
+            }
+        }
     }
 }
 
@@ -108,6 +108,12 @@ impl Theme {
         theme.tab.cli.doc.command = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C800);
         theme.tab.cli.doc.attribute = Color::Tailwind(TailwindColor::PINK, TailwindPalette::C800);
 
+        theme.tab.scripts.accent = Color::Tailwind(TailwindColor::INDIGO, TailwindPalette::C900);
+        theme.tab.scripts.highlight = Color::Tailwind(TailwindColor::INDIGO, TailwindPalette::C700);
+
+        theme.tab.dashboard.accent = Color::Tailwind(TailwindColor::TEAL, TailwindPalette::C900);
+        theme.tab.dashboard.highlight = Color::Tailwind(TailwindColor::TEAL, TailwindPalette::C700);
+
         theme.tab.logger.accent = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C900);
         theme.tab.logger.highlight = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C700);
         theme.tab.logger.level.error = Color::Tailwind(TailwindColor::ROSE, TailwindPalette::C700);
@@ -192,6 +198,12 @@ impl Theme {
         theme.tab.cli.doc.command = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C400);
         theme.tab.cli.doc.attribute = Color::Tailwind(TailwindColor::PINK, TailwindPalette::C800);
 
+        theme.tab.scripts.accent = Color::Tailwind(TailwindColor::INDIGO, TailwindPalette::C900);
+        theme.tab.scripts.highlight = Color::Tailwind(TailwindColor::INDIGO, TailwindPalette::C700);
+
+        theme.tab.dashboard.accent = Color::Tailwind(TailwindColor::TEAL, TailwindPalette::C900);
+        theme.tab.dashboard.highlight = Color::Tailwind(TailwindColor::TEAL, TailwindPalette::C700);
+
         theme.tab.logger.accent = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C900);
         theme.tab.logger.highlight = Color::Tailwind(TailwindColor::AMBER, TailwindPalette::C700);
         theme.tab.logger.level.error = Color::Tailwind(TailwindColor::ROSE, TailwindPalette::C700);
@@ -393,6 +405,8 @@ pub struct Tab {
     pub title: Color,
     pub explorer: Explorer,
     pub cli: Cli,
+    pub scripts: Scripts,
+    pub dashboard: Dashboard,
     pub logger: Logger,
 }
 
@@ -462,6 +476,20 @@ pub struct Doc {
     pub attribute: Color,
 }
 
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+pub struct Scripts {
+    pub accent: Color,
+    pub highlight: Color,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+#[serde(default)]
+pub struct Dashboard {
+    pub accent: Color,
+    pub highlight: Color,
+}
+
 #[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct Logger {
     pub accent: Color,
@@ -28,6 +28,7 @@ pub enum ContentType {
     Json,
     Xml,
     Ron,
+    Lua,
     JavaSerialized,
     Protobuf,
     // PhpSerialized,
@@ -290,6 +291,87 @@ pub fn compare_version_strings(s1: impl Into<String>, s2: impl Into<String>) ->
     std::cmp::Ordering::Equal
 }
 
+/// Size like `INFO` prints it, e.g. `1.50M`.
+pub fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = "K";
+    for next in UNITS.iter().skip(1) {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{size:.2}{unit}")
+}
+
+/// Match a `SCAN MATCH` style pattern: `*`, `?` and `\` escapes, character classes are not supported.
+pub fn glob_match(pattern: &str, s: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let s = s.chars().collect::<Vec<_>>();
+    let (mut p, mut i) = (0, 0);
+    // position after the last `*` and the char it is matched up to
+    let mut backtrack: Option<(usize, usize)> = None;
+    while i < s.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, i));
+                continue;
+            }
+            Some('?') => {
+                p += 1;
+                i += 1;
+                continue;
+            }
+            Some('\\') if pattern.get(p + 1) == s.get(i) => {
+                p += 2;
+                i += 1;
+                continue;
+            }
+            Some(c) if c != &'\\' && Some(c) == s.get(i) => {
+                p += 1;
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        let Some((star_p, star_i)) = backtrack else {
+            return false;
+        };
+        p = star_p;
+        i = star_i + 1;
+        backtrack = Some((star_p, star_i + 1));
+    }
+    pattern.iter().skip(p).all(|c| *c == '*')
+}
+
+/// Cluster hash slot of a key: CRC16 of the `{hash tag}` when it is not empty, of the whole key otherwise.
+pub fn key_slot(key: &[u8]) -> u16 {
+    let hashed = key
+        .iter()
+        .position(|b| *b == b'{')
+        .and_then(|open| {
+            let tag = key.get(open + 1..)?;
+            let close = tag.iter().position(|b| *b == b'}')?;
+            tag.get(..close).filter(|tag| !tag.is_empty())
+        })
+        .unwrap_or(key);
+    // CRC16/XMODEM, as used by redis cluster
+    let mut crc: u16 = 0;
+    for byte in hashed {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc % 16384
+}
+
 pub fn try_decode_arg(arg: &String) -> anyhow::Result<Vec<u8>> {
     let input = arg.clone();
     // Base64#Zm9vIGJhcg==#
@@ -313,7 +395,7 @@ pub fn try_decode_arg(arg: &String) -> anyhow::Result<Vec<u8>> {
 
 #[cfg(test)]
 mod test {
-    use crate::utils::{compare_version_strings, right_pad, try_decode_arg};
+    use crate::utils::{compare_version_strings, glob_match, human_bytes, key_slot, right_pad, try_decode_arg};
     use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
     use std::cmp::Ordering;
 
@@ -347,6 +429,35 @@ mod test {
         assert_eq!(right_pad("a", 3, " "), "a  ");
     }
 
+    #[test]
+    fn test_human_bytes() {
+        assert_eq!(human_bytes(512), "512B");
+        assert_eq!(human_bytes(1536), "1.50K");
+        assert_eq!(human_bytes(3 * 1024 * 1024), "3.00M");
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "user:1"));
+        assert!(glob_match("user:*", "user:1"));
+        assert!(glob_match("*:name", "user:1:name"));
+        assert!(glob_match("*1*", "user:1:name"));
+        assert!(glob_match("user:?", "user:1"));
+        assert!(glob_match("a\\*", "a*"));
+        assert!(!glob_match("a\\*", "ab"));
+        assert!(!glob_match("user:*", "session:1"));
+        assert!(!glob_match("user:?", "user:10"));
+    }
+
+    #[test]
+    fn test_key_slot() {
+        assert_eq!(key_slot(b"123456789"), 12739);
+        assert_eq!(key_slot(b"foo"), 12182);
+        assert_eq!(key_slot(b"{user1000}.following"), key_slot(b"{user1000}.followers"));
+        assert_eq!(key_slot(b"{user1000}.following"), key_slot(b"user1000"));
+        assert_eq!(key_slot(b"foo{}{bar}"), 8363);
+    }
+
     #[test]
     fn test_compare_version() {
         assert_eq!(compare_version_strings("8.0.1", "8.0.1"), Ordering::Equal);
@@ -38,6 +38,14 @@ pub trait TabImplementation: Renderable + Listenable {
     fn title(&self) -> Line<'static>;
 }
 
+/// A view of the dashboard tab, loaded when it is shown.
+pub trait ServerPanel: Renderable + Listenable {
+    fn refresh(&mut self) -> Result<()>;
+
+    /// Drop the data of the previous connection.
+    fn clear(&mut self);
+}
+
 #[allow(unused_variables)]
 pub trait Listenable {
 
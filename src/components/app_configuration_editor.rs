@@ -29,6 +29,8 @@ pub struct Options {
     cli_output_format: OutputFormat,
     console_capacity: TextArea<'static>,
     enable_mouse_capture: bool,
+    dangerous_commands: TextArea<'static>,
+    encrypt_credentials: bool,
 
     form_rect: Rect,
     fps_rect: Rect,
@@ -39,6 +41,7 @@ pub struct Options {
     console_capacity_rect: Rect,
     cli_out_put_format_rect: Rect,
     enable_mouse_capture_rect: Rect,
+    dangerous_commands_rect: Rect,
 }
 
 #[derive(Default, Eq, PartialEq, EnumCount, EnumIter, Display)]
@@ -60,6 +63,8 @@ enum Editing {
     CliOutputFormat,
     #[strum(serialize = "Enable Mouse Capture")]
     EnableMouseCapture,
+    #[strum(serialize = "Dangerous Commands")]
+    DangerousCommands,
 }
 
 #[derive(Default, Eq, PartialEq, EnumCount, EnumIter, Display)]
@@ -87,6 +92,8 @@ impl Default for Options {
             cli_output_format: OutputFormat::default(),
             console_capacity: TextArea::default(),
             enable_mouse_capture: false,
+            dangerous_commands: TextArea::default(),
+            encrypt_credentials: false,
 
             form_rect: Rect::default(),
             fps_rect: Rect::default(),
@@ -97,10 +104,12 @@ impl Default for Options {
             cli_out_put_format_rect: Rect::default(),
             console_capacity_rect: Rect::default(),
             enable_mouse_capture_rect: Rect::default(),
+            dangerous_commands_rect: Rect::default(),
         };
         option
             .fps
             .set_placeholder_style(Style::default().fg(get_color(|t| &t.editor.warning)).dim());
+        option.dangerous_commands.set_placeholder_text("none, comma separated");
         option
     }
 }
@@ -145,11 +154,13 @@ impl Options {
         self.theme.set_cursor_style(Style::default());
         self.history_size.set_cursor_style(Style::default());
         self.console_capacity.set_cursor_style(Style::default());
+        self.dangerous_commands.set_cursor_style(Style::default());
         self.fps.cancel_selection();
         self.scan_size.cancel_selection();
         self.theme.cancel_selection();
         self.history_size.cancel_selection();
         self.console_capacity.cancel_selection();
+        self.dangerous_commands.cancel_selection();
 
         match self.current() {
             Editing::Fps => self.fps.set_cursor_style(cursor_style()),
@@ -157,6 +168,7 @@ impl Options {
             Editing::Theme => self.theme.set_cursor_style(cursor_style()),
             Editing::HistorySize => self.history_size.set_cursor_style(cursor_style()),
             Editing::ConsoleCapacity => self.console_capacity.set_cursor_style(cursor_style()),
+            Editing::DangerousCommands => self.dangerous_commands.set_cursor_style(cursor_style()),
             _ => {}
         }
     }
@@ -257,6 +269,17 @@ impl Options {
         self.enable_mouse_capture_rect = rc[2];
     }
 
+    fn render_dangerous_commands(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(22), Fill(0), Percentage(60)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::DangerousCommands);
+        self.dangerous_commands.set_style(key.style);
+        let value = &self.dangerous_commands;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[2]);
+        self.dangerous_commands_rect = rc[2];
+    }
+
     fn get_fps(&self) -> u8 {
         let first_line = self.fps.lines().get(0).clone().cloned();
         if let Some(first_line) = first_line {
@@ -300,6 +323,16 @@ impl Options {
         self.default_configuration.console_capacity
     }
 
+    fn get_dangerous_commands(&self) -> Vec<String> {
+        self.dangerous_commands
+            .lines()
+            .concat()
+            .split(',')
+            .map(|command| command.trim().to_string())
+            .filter(|command| !command.is_empty())
+            .collect()
+    }
+
     pub fn init_values(&mut self, config: Arc<Configuration>) {
         self.fps.insert_str(config.fps.to_string());
         self.scan_size.insert_str(config.scan_size.to_string());
@@ -315,12 +348,14 @@ impl Options {
         self.console_capacity
             .insert_str(config.console_capacity.to_string());
         self.enable_mouse_capture = config.enable_mouse_capture;
+        self.dangerous_commands.insert_str(config.dangerous_commands.join(", "));
+        self.encrypt_credentials = config.encrypt_credentials;
     }
 }
 
 impl Renderable for Options {
     fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> anyhow::Result<()> {
-        let height = 10;
+        let height = 11;
         let blank_length = (rect.height - height) / 2;
         let area = Layout::vertical([Length(blank_length), Length(height), Length(blank_length)])
             .split(rect)[1];
@@ -344,6 +379,7 @@ impl Renderable for Options {
             Length(1), // console_capacity
             Length(1), // cli_output_format
             Length(1), // enable_mouse_capture
+            Length(1), // dangerous_commands
         ]);
         let rc = vertical.split(block_inner_area);
         self.render_fps(frame, rc[0]);
@@ -354,6 +390,7 @@ impl Renderable for Options {
         self.render_console_capacity(frame, rc[5]);
         self.render_cli_output_format(frame, rc[6]);
         self.render_enable_mouse_capture(frame, rc[7]);
+        self.render_dangerous_commands(frame, rc[8]);
         frame.render_widget(block, area);
         Ok(())
     }
@@ -424,6 +461,8 @@ impl Listenable for Options {
                 },
                 console_capacity,
                 enable_mouse_capture,
+                dangerous_commands: self.get_dangerous_commands(),
+                encrypt_credentials: self.encrypt_credentials,
             };
             save_configuration(&current_configuration)?;
             publish_event(GlobalEvent::Restart)?;
@@ -436,6 +475,7 @@ impl Listenable for Options {
             Editing::Theme => Some(&mut self.scan_size),
             Editing::HistorySize => Some(&mut self.history_size),
             Editing::ConsoleCapacity => Some(&mut self.console_capacity),
+            Editing::DangerousCommands => Some(&mut self.dangerous_commands),
             _ => None,
         };
         if let Some(text_area) = editor {
@@ -618,6 +658,9 @@ impl Listenable for Options {
                     }
                 }
             }
+            if mouse_event.within(&self.dangerous_commands_rect) {
+                self.select(Editing::DangerousCommands);
+            }
             if mouse_event.within(&self.enable_mouse_capture_rect) {
                 self.select(Editing::EnableMouseCapture);
                 if self.enable_mouse_capture {
@@ -0,0 +1,431 @@
+use crate::app::{centered_rect, Listenable, Renderable, ServerPanel};
+use crate::components::popup::Popup;
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length};
+use ratatui::layout::{Alignment, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::block::Position;
+use ratatui::widgets::{Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Table, TableState};
+use ratatui::{symbols, Frame};
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{is_read_only, spawn_redis_opt};
+use ratisui_core::server::ClientInfo;
+use ratisui_core::theme::get_color;
+use ratisui_core::utils::human_bytes;
+use std::time::{Duration, Instant};
+use strum::Display;
+use tui_textarea::TextArea;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// `CLIENT LIST` of every node in one table.
+pub struct ClientsPanel {
+    clients: Vec<ClientInfo>,
+    state: TableState,
+    sort: Sort,
+    descending: bool,
+    filter: String,
+    filter_text_area: Option<TextArea<'static>>,
+    auto_refresh: bool,
+    last_refresh: Option<Instant>,
+    loading: bool,
+    /// Client waiting for the kill to be confirmed.
+    killing: Option<ClientInfo>,
+    colors: TableColors,
+    data_sender: Sender<Data>,
+    data_receiver: Receiver<Data>,
+
+    table_rect: Rect,
+}
+
+enum Data {
+    Clients(Vec<ClientInfo>),
+    Failed,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Display)]
+enum Sort {
+    Id,
+    Age,
+    Idle,
+    Memory,
+    Addr,
+}
+
+impl Sort {
+    const fn next(self) -> Self {
+        match self {
+            Self::Id => Self::Age,
+            Self::Age => Self::Idle,
+            Self::Idle => Self::Memory,
+            Self::Memory => Self::Addr,
+            Self::Addr => Self::Id,
+        }
+    }
+}
+
+impl ClientsPanel {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            clients: vec![],
+            state: TableState::default(),
+            sort: Sort::Id,
+            descending: false,
+            filter: String::new(),
+            filter_text_area: None,
+            auto_refresh: false,
+            last_refresh: None,
+            loading: false,
+            killing: None,
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            table_rect: Rect::default(),
+        }
+    }
+
+    fn kill(&mut self, client: ClientInfo) -> Result<()> {
+        spawn_redis_opt(move |operations| async move {
+            match operations.client_kill(&client).await {
+                Ok(_) => {
+                    let _ = publish_msg(Message::info(format!("Client {} ({}) killed", client.id, client.addr)));
+                }
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to kill client {}: {}", client.id, e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })?;
+        self.refresh()
+    }
+
+    /// Clients matching the filter in the chosen order.
+    fn visible_clients(&self) -> Vec<&ClientInfo> {
+        let mut clients = self
+            .clients
+            .iter()
+            .filter(|client| self.filter.is_empty() || client.matches(&self.filter))
+            .collect::<Vec<_>>();
+        match self.sort {
+            Sort::Id => clients.sort_by(|a, b| a.node.addr.cmp(&b.node.addr).then(a.id.cmp(&b.id))),
+            Sort::Age => clients.sort_by_key(|client| client.age),
+            Sort::Idle => clients.sort_by_key(|client| client.idle),
+            Sort::Memory => clients.sort_by_key(|client| client.memory),
+            Sort::Addr => clients.sort_by(|a, b| a.addr.cmp(&b.addr)),
+        }
+        if self.descending {
+            clients.reverse();
+        }
+        clients
+    }
+
+    fn selected_client(&self) -> Option<ClientInfo> {
+        self.state
+            .selected()
+            .and_then(|i| self.visible_clients().get(i).map(|client| (*client).clone()))
+    }
+
+    fn next(&mut self) {
+        let len = self.visible_clients().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        let len = self.visible_clients().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn select_first(&mut self) {
+        let selected = if self.visible_clients().is_empty() { None } else { Some(0) };
+        self.state.select(selected);
+    }
+
+    fn render_status(&mut self, frame: &mut Frame, rect: Rect) {
+        if let Some(text_area) = &mut self.filter_text_area {
+            let [label_area, input_area] = Layout::horizontal([Length(8), Fill(1)]).areas(rect);
+            frame.render_widget(Span::raw("Filter: ").bold(), label_area);
+            text_area.set_cursor_line_style(Style::default());
+            frame.render_widget(&*text_area, input_area);
+            return;
+        }
+        let visible = self.visible_clients().len();
+        let mut spans = vec![
+            Span::raw(format!("{visible}/{} clients", self.clients.len())),
+            Span::raw(format!(" | sort by {} {}", self.sort, if self.descending { "↓" } else { "↑" })),
+        ];
+        if !self.filter.is_empty() {
+            spans.push(Span::raw(format!(" | filter \"{}\"", self.filter)));
+        }
+        if self.auto_refresh {
+            spans.push(Span::raw(format!(" | auto refresh {}s", REFRESH_INTERVAL.as_secs())));
+        }
+        if self.loading {
+            spans.push(Span::raw(" | loading...").dim());
+        }
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, rect: Rect) {
+        let clients = self.visible_clients();
+        let show_node = self
+            .clients
+            .first()
+            .is_some_and(|first| self.clients.iter().any(|client| client.node.addr != first.node.addr));
+        let mut titles = vec!["Id", "Addr", "Name", "Age(s)", "Idle(s)", "Db", "Flags", "Cmd", "Memory"];
+        let mut widths = vec![
+            Length(8),
+            Length(21),
+            Fill(1),
+            Length(8),
+            Length(8),
+            Length(3),
+            Length(6),
+            Fill(1),
+            Length(8),
+        ];
+        if show_node {
+            titles.insert(0, "Node");
+            widths.insert(0, Length(21));
+        }
+        let header = titles
+            .into_iter()
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(Style::default().bold().fg(self.colors.header_fg).bg(self.colors.header_bg))
+            .height(1);
+        let rows = clients
+            .iter()
+            .enumerate()
+            .map(|(i, client)| {
+                let mut cells = vec![
+                    client.id.to_string(),
+                    client.addr.clone(),
+                    client.name.clone(),
+                    client.age.to_string(),
+                    client.idle.to_string(),
+                    client.db.to_string(),
+                    client.flags.clone(),
+                    client.cmd.clone(),
+                    human_bytes(client.memory),
+                ];
+                if show_node {
+                    cells.insert(0, client.node.addr.clone());
+                }
+                let bg = if i % 2 == 0 { self.colors.normal_row } else { self.colors.alt_row };
+                cells
+                    .into_iter()
+                    .map(|content| Cell::from(Text::raw(content)))
+                    .collect::<Row>()
+                    .style(Style::new().fg(self.colors.row_fg).bg(bg))
+                    .height(1)
+            })
+            .collect::<Vec<_>>();
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+            .highlight_symbol(Text::raw("➤ "))
+            .bg(self.colors.bg)
+            .column_spacing(1)
+            .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut self.state);
+    }
+
+    fn render_kill_popup(&self, frame: &mut Frame, rect: Rect) {
+        if let Some(client) = &self.killing {
+            let popup_area = centered_rect(40, 20, rect);
+            let mut text = Text::default();
+            text.push_line(Line::raw(format!("CLIENT KILL ID {}", client.id)).alignment(Alignment::Center).bold());
+            text.push_line(Line::default());
+            let name = if client.name.is_empty() { String::new() } else { format!(" ({})", client.name) };
+            text.push_line(Line::raw(format!("{}{name}", client.addr)).alignment(Alignment::Center));
+            text.push_line(Line::raw("Are you sure?").alignment(Alignment::Center).bold());
+            let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+            let popup = Popup::new(paragraph)
+                .title(String::from(" [Enter] Kill | [Esc] Cancel "))
+                .title_position(Position::Bottom)
+                .borders(Borders::ALL)
+                .border_set(symbols::border::DOUBLE)
+                .border_style(Style::default().fg(get_color(|t| &t.toast.warn)));
+            frame.render_widget(popup, popup_area);
+        }
+    }
+}
+
+impl ServerPanel for ClientsPanel {
+    fn refresh(&mut self) -> Result<()> {
+        self.last_refresh = Some(Instant::now());
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.client_list().await {
+                Ok(clients) => sender.send(Data::Clients(clients))?,
+                Err(e) => {
+                    sender.send(Data::Failed)?;
+                    let _ = publish_msg(Message::error(format!("Failed to list clients: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn clear(&mut self) {
+        self.clients.clear();
+        self.state.select(None);
+        self.killing = None;
+        self.last_refresh = None;
+    }
+}
+
+impl Renderable for ClientsPanel {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        let block = Block::bordered()
+            .title("Clients")
+            .border_style(get_color(|t| &t.border.default));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        let [status_area, table_area] = Layout::vertical([Length(1), Fill(1)]).areas(inner);
+        self.table_rect = table_area;
+        self.render_status(frame, status_area);
+        self.render_table(frame, table_area);
+        self.render_kill_popup(frame, rect);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        if self.killing.is_some() {
+            return vec![("Enter", "Kill"), ("Esc", "Cancel")];
+        }
+        if self.filter_text_area.is_some() {
+            return vec![("Enter", "Apply"), ("Esc", "Clear")];
+        }
+        vec![
+            ("↓/j", "Down"),
+            ("↑/k", "Up"),
+            ("s", "Sort"),
+            ("S", "Reverse"),
+            ("/", "Filter"),
+            ("a", "Auto Refresh"),
+            ("x", "Kill"),
+            ("F5", "Refresh"),
+        ]
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(data) = self.data_receiver.try_recv() {
+            self.loading = false;
+            if let Data::Clients(clients) = data {
+                self.clients = clients;
+                let len = self.visible_clients().len();
+                let selected = match self.state.selected() {
+                    _ if len == 0 => None,
+                    Some(i) => Some(i.min(len - 1)),
+                    None => Some(0),
+                };
+                self.state.select(selected);
+            }
+            needed = true;
+        }
+        if self.auto_refresh
+            && !self.loading
+            && self.killing.is_none()
+            && self.last_refresh.is_none_or(|last_refresh| last_refresh.elapsed() >= REFRESH_INTERVAL)
+        {
+            self.refresh()?;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for ClientsPanel {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if let Some(client) = self.killing.take() {
+            if key_event.code == KeyCode::Enter {
+                self.kill(client)?;
+            } else if key_event.code != KeyCode::Esc {
+                self.killing = Some(client);
+            }
+            return Ok(true);
+        }
+        if let Some(text_area) = &mut self.filter_text_area {
+            match key_event.code {
+                KeyCode::Enter => self.filter_text_area = None,
+                KeyCode::Esc => {
+                    self.filter_text_area = None;
+                    self.filter.clear();
+                }
+                _ => {
+                    text_area.input(key_event);
+                    self.filter = text_area.lines().concat();
+                }
+            }
+            self.select_first();
+            return Ok(true);
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => self.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.previous(),
+            KeyCode::Char('s') => {
+                self.sort = self.sort.next();
+                self.select_first();
+            }
+            KeyCode::Char('S') => {
+                self.descending = !self.descending;
+                self.select_first();
+            }
+            KeyCode::Char('/') => {
+                let mut text_area = TextArea::default();
+                text_area.set_placeholder_text("addr, name, flags or command");
+                text_area.insert_str(&self.filter);
+                self.filter_text_area = Some(text_area);
+            }
+            KeyCode::Char('a') => self.auto_refresh = !self.auto_refresh,
+            KeyCode::Char('x') => {
+                if is_read_only() {
+                    let _ = publish_msg(Message::warning("(read-only) CLIENT KILL is rejected"));
+                } else {
+                    self.killing = self.selected_client();
+                }
+            }
+            KeyCode::F(5) => self.refresh()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if self.table_rect.contains(mouse_event.as_position()) {
+            if mouse_event.is_scroll_down() {
+                self.next();
+            } else if mouse_event.is_scroll_up() {
+                self.previous();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
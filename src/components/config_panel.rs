@@ -0,0 +1,497 @@
+use crate::app::{centered_rect, Listenable, Renderable, ServerPanel};
+use crate::components::popup::Popup;
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length};
+use ratatui::layout::{Alignment, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::block::Position;
+use ratatui::widgets::{Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{symbols, Frame};
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{is_read_only, redis_operations, spawn_redis_opt};
+use ratisui_core::server::{is_immutable_config_error, ConfigParam, CONFIG_DEFAULTS_VERSION};
+use ratisui_core::theme::get_color;
+use std::collections::{HashMap, HashSet};
+use tui_textarea::TextArea;
+
+/// `CONFIG GET *` with in-place `CONFIG SET` and `CONFIG REWRITE`, on every master in cluster mode.
+pub struct ConfigPanel {
+    params: Vec<ConfigParam>,
+    state: TableState,
+    filter: String,
+    filter_text_area: Option<TextArea<'static>>,
+    /// Only show the parameters that differ from their redis 7.2 default.
+    modified_only: bool,
+    /// Parameter being edited with its new value.
+    value_text_area: Option<(String, TextArea<'static>)>,
+    /// `CONFIG SET` waiting for the confirmation, with the new value.
+    setting: Option<(ConfigParam, String)>,
+    confirm_rewrite: bool,
+    /// Parameters `CONFIG SET` refused as immutable per workspace, for versions without a known list.
+    immutable: HashMap<String, HashSet<String>>,
+    loading: bool,
+    colors: TableColors,
+    data_sender: Sender<Data>,
+    data_receiver: Receiver<Data>,
+
+    table_rect: Rect,
+}
+
+enum Data {
+    Params(Vec<ConfigParam>),
+    /// Workspace and parameter refused as immutable.
+    Immutable(String, String),
+    Failed,
+}
+
+impl ConfigPanel {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            params: vec![],
+            state: TableState::default(),
+            filter: String::new(),
+            filter_text_area: None,
+            modified_only: false,
+            value_text_area: None,
+            setting: None,
+            confirm_rewrite: false,
+            immutable: HashMap::new(),
+            loading: false,
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            table_rect: Rect::default(),
+        }
+    }
+
+    fn set(&mut self, param: ConfigParam, value: String) -> Result<()> {
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.config_set(&param.name, &value).await {
+                Ok(()) => {
+                    let _ = publish_msg(Message::info(format!("{} set to \"{}\"", param.name, value)));
+                }
+                Err(e) => {
+                    if is_immutable_config_error(&e.to_string()) {
+                        sender.send(Data::Immutable(operations.name.clone(), param.name.clone()))?;
+                    }
+                    let _ = publish_msg(Message::error(format!("Failed to set {}: {}", param.name, e)));
+                }
+            }
+            // the server may normalize the value, e.g. `1gb` is reported in bytes
+            match operations.config_params().await {
+                Ok(params) => sender.send(Data::Params(params))?,
+                Err(_) => sender.send(Data::Failed)?,
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn rewrite(&self) -> Result<()> {
+        spawn_redis_opt(move |operations| async move {
+            match operations.config_rewrite().await {
+                Ok(()) => {
+                    let _ = publish_msg(Message::info("Config file rewritten"));
+                }
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to rewrite the config file: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    /// Parameters refused so far by the server of the active workspace.
+    fn learned_immutable(&self) -> Option<&HashSet<String>> {
+        redis_operations().and_then(|operations| self.immutable.get(&operations.name))
+    }
+
+    fn is_immutable(param: &ConfigParam, learned: Option<&HashSet<String>>) -> bool {
+        param.immutable || learned.is_some_and(|learned| learned.contains(&param.name))
+    }
+
+    /// Defaults are only attached on the redis release they were taken from.
+    fn has_defaults(&self) -> bool {
+        self.params.iter().any(|param| param.default.is_some())
+    }
+
+    /// Parameters matching the filter, sorted by name.
+    fn visible_params(&self) -> Vec<&ConfigParam> {
+        self.params
+            .iter()
+            .filter(|param| !self.modified_only || param.is_modified())
+            .filter(|param| self.filter.is_empty() || param.matches(&self.filter))
+            .collect()
+    }
+
+    fn selected_param(&self) -> Option<ConfigParam> {
+        self.state
+            .selected()
+            .and_then(|i| self.visible_params().get(i).map(|param| (*param).clone()))
+    }
+
+    fn edit(&mut self) {
+        let Some(param) = self.selected_param() else {
+            return;
+        };
+        if is_read_only() {
+            let _ = publish_msg(Message::warning("(read-only) CONFIG SET is rejected"));
+        } else if Self::is_immutable(&param, self.learned_immutable()) {
+            let _ = publish_msg(Message::warning(format!("{} is immutable, it can only be changed in the config file", param.name)));
+        } else {
+            let mut text_area = TextArea::default();
+            text_area.insert_str(&param.value);
+            self.value_text_area = Some((param.name, text_area));
+        }
+    }
+
+    fn apply_value(&mut self) {
+        if let Some((name, text_area)) = self.value_text_area.take()
+            && let Some(param) = self.params.iter().find(|param| param.name == name)
+        {
+            let value = text_area.lines().concat();
+            if value != param.value {
+                self.setting = Some((param.clone(), value));
+            }
+        }
+    }
+
+    fn next(&mut self) {
+        let len = self.visible_params().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        let len = self.visible_params().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn select_first(&mut self) {
+        let selected = if self.visible_params().is_empty() { None } else { Some(0) };
+        self.state.select(selected);
+    }
+
+    fn render_status(&mut self, frame: &mut Frame, rect: Rect) {
+        if let Some((name, text_area)) = &mut self.value_text_area {
+            let label = format!("{name}: ");
+            let width = u16::try_from(label.chars().count()).unwrap_or(rect.width);
+            let [label_area, input_area] = Layout::horizontal([Length(width), Fill(1)]).areas(rect);
+            frame.render_widget(Span::raw(label).bold(), label_area);
+            text_area.set_cursor_line_style(Style::default());
+            frame.render_widget(&*text_area, input_area);
+            return;
+        }
+        if let Some(text_area) = &mut self.filter_text_area {
+            let [label_area, input_area] = Layout::horizontal([Length(8), Fill(1)]).areas(rect);
+            frame.render_widget(Span::raw("Filter: ").bold(), label_area);
+            text_area.set_cursor_line_style(Style::default());
+            frame.render_widget(&*text_area, input_area);
+            return;
+        }
+        let visible = self.visible_params().len();
+        let modified = self.params.iter().filter(|param| param.is_modified()).count();
+        let mut spans = vec![
+            Span::raw(format!("{visible}/{} parameters", self.params.len())),
+            Span::raw(format!(" | {modified} modified")),
+        ];
+        if self.modified_only {
+            spans.push(Span::raw(format!(" | diff against redis {CONFIG_DEFAULTS_VERSION} defaults")));
+        }
+        let diverging = self.params.iter().filter(|param| param.diverges).count();
+        if diverging > 0 {
+            spans.push(Span::raw(format!(" | {diverging} differ between masters")));
+        }
+        if !self.filter.is_empty() {
+            spans.push(Span::raw(format!(" | filter \"{}\"", self.filter)));
+        }
+        if self.loading {
+            spans.push(Span::raw(" | loading...").dim());
+        }
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, rect: Rect) {
+        let params = self.visible_params();
+        let learned = self.learned_immutable();
+        let default_title = format!("Default ({CONFIG_DEFAULTS_VERSION})");
+        let header = ["Name", "Value", default_title.as_str(), "State"]
+            .into_iter()
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(Style::default().bold().fg(self.colors.header_fg).bg(self.colors.header_bg))
+            .height(1);
+        let rows = params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let immutable = Self::is_immutable(param, learned);
+                let value = if param.is_modified() {
+                    Cell::from(Text::raw(param.value.clone()).fg(get_color(|t| &t.toast.warn)))
+                } else {
+                    Cell::from(Text::raw(param.value.clone()))
+                };
+                let state = match (immutable, param.diverges, param.is_modified()) {
+                    (true, _, _) => "immutable",
+                    (false, true, _) => "differs",
+                    (false, false, true) => "modified",
+                    (false, false, false) => "",
+                };
+                let bg = if i % 2 == 0 { self.colors.normal_row } else { self.colors.alt_row };
+                let name = Text::raw(param.name.clone());
+                Row::new(vec![
+                    Cell::from(if immutable { name.dim() } else { name }),
+                    value,
+                    Cell::from(Text::raw(param.default.unwrap_or("-"))),
+                    Cell::from(Text::raw(state)),
+                ])
+                .style(Style::new().fg(self.colors.row_fg).bg(bg))
+                .height(1)
+            })
+            .collect::<Vec<_>>();
+        let table = Table::new(rows, [Length(32), Fill(2), Fill(1), Length(9)])
+            .header(header)
+            .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+            .highlight_symbol(Text::raw("➤ "))
+            .bg(self.colors.bg)
+            .column_spacing(1)
+            .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut self.state);
+    }
+
+    fn render_confirm_popup(&self, frame: &mut Frame, rect: Rect) {
+        let (text, title) = if let Some((param, value)) = &self.setting {
+            let mut text = Text::default();
+            text.push_line(Line::raw(format!("CONFIG SET {}", param.name)).alignment(Alignment::Center).bold());
+            text.push_line(Line::default());
+            text.push_line(Line::raw(format!("\"{}\" → \"{}\"", param.value, value)).alignment(Alignment::Center));
+            text.push_line(Line::raw("Are you sure?").alignment(Alignment::Center).bold());
+            (text, " [Enter] Set | [Esc] Cancel ")
+        } else if self.confirm_rewrite {
+            let mut text = Text::default();
+            text.push_line(Line::raw("CONFIG REWRITE").alignment(Alignment::Center).bold());
+            text.push_line(Line::default());
+            text.push_line(
+                Line::raw("The config file will be overwritten with the running configuration.")
+                    .alignment(Alignment::Center),
+            );
+            text.push_line(Line::raw("Are you sure?").alignment(Alignment::Center).bold());
+            (text, " [Enter] Rewrite | [Esc] Cancel ")
+        } else {
+            return;
+        };
+        let popup_area = centered_rect(50, 20, rect);
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center).wrap(Wrap { trim: false });
+        let popup = Popup::new(paragraph)
+            .title(String::from(title))
+            .title_position(Position::Bottom)
+            .borders(Borders::ALL)
+            .border_set(symbols::border::DOUBLE)
+            .border_style(Style::default().fg(get_color(|t| &t.toast.warn)));
+        frame.render_widget(popup, popup_area);
+    }
+}
+
+impl ServerPanel for ConfigPanel {
+    fn refresh(&mut self) -> Result<()> {
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.config_params().await {
+                Ok(params) => sender.send(Data::Params(params))?,
+                Err(e) => {
+                    sender.send(Data::Failed)?;
+                    let _ = publish_msg(Message::error(format!("Failed to read config: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn clear(&mut self) {
+        self.params.clear();
+        self.state.select(None);
+        self.value_text_area = None;
+        self.setting = None;
+        self.confirm_rewrite = false;
+    }
+}
+
+impl Renderable for ConfigPanel {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        let block = Block::bordered()
+            .title("Config")
+            .border_style(get_color(|t| &t.border.default));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        let [status_area, table_area] = Layout::vertical([Length(1), Fill(1)]).areas(inner);
+        self.table_rect = table_area;
+        self.render_status(frame, status_area);
+        self.render_table(frame, table_area);
+        self.render_confirm_popup(frame, rect);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        if self.setting.is_some() {
+            return vec![("Enter", "Set"), ("Esc", "Cancel")];
+        }
+        if self.confirm_rewrite {
+            return vec![("Enter", "Rewrite"), ("Esc", "Cancel")];
+        }
+        if self.value_text_area.is_some() {
+            return vec![("Enter", "Apply"), ("Esc", "Cancel")];
+        }
+        if self.filter_text_area.is_some() {
+            return vec![("Enter", "Apply"), ("Esc", "Clear")];
+        }
+        vec![
+            ("↓/j", "Down"),
+            ("↑/k", "Up"),
+            ("/", "Filter"),
+            ("d", if self.modified_only { "All" } else { "Diff" }),
+            ("e/Enter", "Edit"),
+            ("w", "Rewrite"),
+            ("F5", "Refresh"),
+        ]
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(data) = self.data_receiver.try_recv() {
+            match data {
+                Data::Params(params) => {
+                    self.loading = false;
+                    self.params = params;
+                    let len = self.visible_params().len();
+                    let selected = match self.state.selected() {
+                        _ if len == 0 => None,
+                        Some(i) => Some(i.min(len - 1)),
+                        None => Some(0),
+                    };
+                    self.state.select(selected);
+                }
+                Data::Immutable(workspace, name) => {
+                    self.immutable.entry(workspace).or_default().insert(name);
+                }
+                Data::Failed => self.loading = false,
+            }
+            needed = true;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for ConfigPanel {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if let Some((param, value)) = self.setting.take() {
+            if key_event.code == KeyCode::Enter {
+                self.set(param, value)?;
+            } else if key_event.code != KeyCode::Esc {
+                self.setting = Some((param, value));
+            }
+            return Ok(true);
+        }
+        if self.confirm_rewrite {
+            if key_event.code == KeyCode::Enter {
+                self.confirm_rewrite = false;
+                self.rewrite()?;
+            } else if key_event.code == KeyCode::Esc {
+                self.confirm_rewrite = false;
+            }
+            return Ok(true);
+        }
+        if let Some((_, text_area)) = &mut self.value_text_area {
+            match key_event.code {
+                KeyCode::Enter => self.apply_value(),
+                KeyCode::Esc => self.value_text_area = None,
+                _ => {
+                    text_area.input(key_event);
+                }
+            }
+            return Ok(true);
+        }
+        if let Some(text_area) = &mut self.filter_text_area {
+            match key_event.code {
+                KeyCode::Enter => self.filter_text_area = None,
+                KeyCode::Esc => {
+                    self.filter_text_area = None;
+                    self.filter.clear();
+                }
+                _ => {
+                    text_area.input(key_event);
+                    self.filter = text_area.lines().concat();
+                }
+            }
+            self.select_first();
+            return Ok(true);
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => self.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.previous(),
+            KeyCode::Char('/') => {
+                let mut text_area = TextArea::default();
+                text_area.set_placeholder_text("name or value");
+                text_area.insert_str(&self.filter);
+                self.filter_text_area = Some(text_area);
+            }
+            KeyCode::Char('d') => {
+                if self.modified_only || self.has_defaults() {
+                    self.modified_only = !self.modified_only;
+                    self.select_first();
+                } else {
+                    let _ = publish_msg(Message::warning(format!(
+                        "Defaults are only known for redis {CONFIG_DEFAULTS_VERSION}, nothing to diff against"
+                    )));
+                }
+            }
+            KeyCode::Char('e') | KeyCode::Enter => self.edit(),
+            KeyCode::Char('w') => {
+                if is_read_only() {
+                    let _ = publish_msg(Message::warning("(read-only) CONFIG REWRITE is rejected"));
+                } else {
+                    self.confirm_rewrite = true;
+                }
+            }
+            KeyCode::F(5) => self.refresh()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if self.table_rect.contains(mouse_event.as_position()) {
+            if mouse_event.is_scroll_down() {
+                self.next();
+            } else if mouse_event.is_scroll_up() {
+                self.previous();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
 use crate::app::{Listenable, Renderable};
 use crate::components::servers::Data;
-use ratisui_core::configuration::{Database, Protocol, SshTunnel};
+use ratisui_core::configuration::{Database, JumpHost, Protocol, Sentinel, SshAuth, SshTunnel, Tls};
+use ratisui_core::credentials::ENV_PREFIX;
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use ratatui::layout::Constraint::{Fill, Length, Percentage};
 use ratatui::layout::{Layout, Rect};
@@ -10,7 +11,7 @@ use ratatui::text::Span;
 use ratatui::widgets::{Block, BorderType};
 use ratatui::Frame;
 use strum::{Display, EnumCount, EnumIter, IntoEnumIterator};
-use tui_textarea::TextArea;
+use tui_textarea::{CursorMove, TextArea};
 use uuid::Uuid;
 use ratisui_core::mouse::MouseEventHelper;
 use ratisui_core::theme::get_color;
@@ -21,35 +22,66 @@ pub struct Form {
     editing_style: Style,
     editing: usize,
     name_text_area: TextArea<'static>,
+    connection: Connection,
     host_text_area: TextArea<'static>,
     port_text_area: TextArea<'static>,
+    sentinel_master_name_text_area: TextArea<'static>,
+    sentinel_addresses_text_area: TextArea<'static>,
+    unix_socket_path_text_area: TextArea<'static>,
     enabled_authentication: bool,
     username_text_area: TextArea<'static>,
     password_text_area: TextArea<'static>,
     use_tls: bool,
+    tls_ca_cert_text_area: TextArea<'static>,
+    tls_client_cert_text_area: TextArea<'static>,
+    tls_client_key_text_area: TextArea<'static>,
+    tls_insecure: bool,
     db_text_area: TextArea<'static>,
     protocol: Protocol,
+    read_only: bool,
     use_ssh_tunnel: bool,
+    ssh_hops: Vec<JumpHost>,
+    ssh_hop_index: usize,
     ssh_host_text_area: TextArea<'static>,
     ssh_port_text_area: TextArea<'static>,
     ssh_username_text_area: TextArea<'static>,
+    ssh_auth: SshAuthKind,
     ssh_password_text_area: TextArea<'static>,
+    ssh_key_path_text_area: TextArea<'static>,
+    ssh_key_passphrase_text_area: TextArea<'static>,
+    ssh_agent_socket_text_area: TextArea<'static>,
+    ssh_known_hosts_text_area: TextArea<'static>,
 
     form_rect: Rect,
     name_rect: Rect,
+    connection_rect: Rect,
     host_rect: Rect,
     port_rect: Rect,
+    sentinel_master_name_rect: Rect,
+    sentinel_addresses_rect: Rect,
+    unix_socket_path_rect: Rect,
     authentication_rect: Rect,
     username_rect: Rect,
     password_rect: Rect,
     tls_rect: Rect,
+    tls_ca_cert_rect: Rect,
+    tls_client_cert_rect: Rect,
+    tls_client_key_rect: Rect,
+    tls_insecure_rect: Rect,
     db_rect: Rect,
     protocol_rect: Rect,
+    read_only_rect: Rect,
     use_ssh_rect: Rect,
+    ssh_hop_rect: Rect,
     ssh_host_rect: Rect,
     ssh_port_rect: Rect,
     ssh_username_rect: Rect,
+    ssh_auth_rect: Rect,
     ssh_password_rect: Rect,
+    ssh_key_path_rect: Rect,
+    ssh_key_passphrase_rect: Rect,
+    ssh_agent_socket_rect: Rect,
+    ssh_known_hosts_rect: Rect,
 }
 
 #[derive(Default, Eq, PartialEq, EnumCount, EnumIter, Display)]
@@ -57,10 +89,18 @@ enum Editing {
     #[default]
     #[strum(serialize = "Name(*)")]
     Name,
+    #[strum(serialize = "Connection")]
+    Connection,
     #[strum(serialize = "Host")]
     Host,
     #[strum(serialize = "Port")]
     Port,
+    #[strum(serialize = "Master Name")]
+    SentinelMasterName,
+    #[strum(serialize = "Sentinels")]
+    SentinelAddresses,
+    #[strum(serialize = "Socket Path")]
+    UnixSocketPath,
     #[strum(serialize = "Authentication")]
     EnabledAuthentication,
     #[strum(serialize = "Username")]
@@ -69,20 +109,140 @@ enum Editing {
     Password,
     #[strum(serialize = "Use TLS")]
     UseTls,
+    #[strum(serialize = "CA Cert")]
+    TlsCaCert,
+    #[strum(serialize = "Client Cert")]
+    TlsClientCert,
+    #[strum(serialize = "Client Key")]
+    TlsClientKey,
+    #[strum(serialize = "Skip Verify")]
+    TlsInsecure,
     #[strum(serialize = "Database")]
     Db,
     #[strum(serialize = "Protocol")]
     Protocol,
+    #[strum(serialize = "Read Only")]
+    ReadOnly,
     #[strum(serialize = "Use SSH Tunnel")]
     UseSshTunnel,
+    #[strum(serialize = "SSH Hop")]
+    SshHop,
     #[strum(serialize = "SSH Host")]
     SshHost,
     #[strum(serialize = "SSH Port")]
     SshPort,
     #[strum(serialize = "SSH Username")]
     SshUsername,
+    #[strum(serialize = "SSH Auth")]
+    SshAuth,
     #[strum(serialize = "SSH Password")]
     SshPassword,
+    #[strum(serialize = "SSH Key")]
+    SshKeyPath,
+    #[strum(serialize = "Key Passphrase")]
+    SshKeyPassphrase,
+    #[strum(serialize = "Agent Socket")]
+    SshAgentSocket,
+    #[strum(serialize = "Known Hosts")]
+    SshKnownHosts,
+}
+
+#[derive(Default, Clone, Copy, Eq, PartialEq, Display)]
+enum Connection {
+    #[default]
+    #[strum(serialize = "TCP")]
+    Tcp,
+    #[strum(serialize = "Sentinel")]
+    Sentinel,
+    #[strum(serialize = "Unix Socket")]
+    UnixSocket,
+}
+
+impl Connection {
+    fn next(self) -> Self {
+        match self {
+            Connection::Tcp => Connection::Sentinel,
+            Connection::Sentinel => Connection::UnixSocket,
+            Connection::UnixSocket => Connection::Tcp,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            Connection::Tcp => Connection::UnixSocket,
+            Connection::Sentinel => Connection::Tcp,
+            Connection::UnixSocket => Connection::Sentinel,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, Eq, PartialEq, Display)]
+enum SshAuthKind {
+    #[default]
+    #[strum(serialize = "Password")]
+    Password,
+    #[strum(serialize = "Private Key")]
+    PrivateKey,
+    #[strum(serialize = "Agent")]
+    Agent,
+}
+
+impl SshAuthKind {
+    fn next(self) -> Self {
+        match self {
+            SshAuthKind::Password => SshAuthKind::PrivateKey,
+            SshAuthKind::PrivateKey => SshAuthKind::Agent,
+            SshAuthKind::Agent => SshAuthKind::Password,
+        }
+    }
+
+    fn prev(self) -> Self {
+        match self {
+            SshAuthKind::Password => SshAuthKind::Agent,
+            SshAuthKind::PrivateKey => SshAuthKind::Password,
+            SshAuthKind::Agent => SshAuthKind::PrivateKey,
+        }
+    }
+}
+
+fn set_text(text_area: &mut TextArea<'static>, text: impl Into<String>) {
+    text_area.move_cursor(CursorMove::End);
+    text_area.delete_line_by_head();
+    text_area.insert_str(text.into());
+}
+
+/// Password fields show a password command as `cmd:<command>`.
+const COMMAND_PREFIX: &str = "cmd:";
+
+fn password_text(password: Option<String>, command: Option<String>) -> String {
+    match command {
+        Some(command) => format!("{COMMAND_PREFIX}{command}"),
+        None => password.unwrap_or_default(),
+    }
+}
+
+/// Split a password field into the password and the password command.
+fn parse_password_text(text: Option<String>) -> (Option<String>, Option<String>) {
+    match text {
+        Some(text) => match text.strip_prefix(COMMAND_PREFIX) {
+            Some(command) => (None, Some(command.to_string())),
+            None => (Some(text), None),
+        },
+        None => (None, None),
+    }
+}
+
+/// Only mask actual secrets, `env:` and `cmd:` sources stay readable.
+fn mask_secret(text_area: &mut TextArea<'static>) {
+    let is_source = text_area
+        .lines()
+        .first()
+        .is_some_and(|line| line.starts_with(ENV_PREFIX) || line.starts_with(COMMAND_PREFIX));
+    if is_source {
+        text_area.clear_mask_char();
+    } else {
+        text_area.set_mask_char('•');
+    }
 }
 
 fn cursor_style() -> Style {
@@ -96,63 +256,115 @@ impl Default for Form {
             editing_style: Style::default().fg(get_color(|t| &t.editor.editing)),
             editing: 0,
             name_text_area: TextArea::default(),
+            connection: Connection::Tcp,
             host_text_area: TextArea::default(),
             port_text_area: TextArea::default(),
+            sentinel_master_name_text_area: TextArea::default(),
+            sentinel_addresses_text_area: TextArea::default(),
+            unix_socket_path_text_area: TextArea::default(),
             enabled_authentication: true,
             username_text_area: TextArea::default(),
             password_text_area: TextArea::default(),
             use_tls: false,
+            tls_ca_cert_text_area: TextArea::default(),
+            tls_client_cert_text_area: TextArea::default(),
+            tls_client_key_text_area: TextArea::default(),
+            tls_insecure: false,
             db_text_area: TextArea::default(),
             protocol: Protocol::RESP3,
+            read_only: false,
             use_ssh_tunnel: false,
+            ssh_hops: vec![JumpHost::default()],
+            ssh_hop_index: 0,
             ssh_host_text_area: TextArea::default(),
             ssh_port_text_area: TextArea::default(),
             ssh_username_text_area: TextArea::default(),
+            ssh_auth: SshAuthKind::Password,
             ssh_password_text_area: TextArea::default(),
+            ssh_key_path_text_area: TextArea::default(),
+            ssh_key_passphrase_text_area: TextArea::default(),
+            ssh_agent_socket_text_area: TextArea::default(),
+            ssh_known_hosts_text_area: TextArea::default(),
 
             form_rect: Default::default(),
             name_rect: Default::default(),
+            connection_rect: Default::default(),
             host_rect: Default::default(),
             port_rect: Default::default(),
+            sentinel_master_name_rect: Default::default(),
+            sentinel_addresses_rect: Default::default(),
+            unix_socket_path_rect: Default::default(),
             authentication_rect: Default::default(),
             username_rect: Default::default(),
             password_rect: Default::default(),
             tls_rect: Default::default(),
+            tls_ca_cert_rect: Default::default(),
+            tls_client_cert_rect: Default::default(),
+            tls_client_key_rect: Default::default(),
+            tls_insecure_rect: Default::default(),
             db_rect: Default::default(),
             protocol_rect: Default::default(),
+            read_only_rect: Default::default(),
             use_ssh_rect: Default::default(),
+            ssh_hop_rect: Default::default(),
             ssh_host_rect: Default::default(),
             ssh_port_rect: Default::default(),
             ssh_username_rect: Default::default(),
+            ssh_auth_rect: Default::default(),
             ssh_password_rect: Default::default(),
+            ssh_key_path_rect: Default::default(),
+            ssh_key_passphrase_rect: Default::default(),
+            ssh_agent_socket_rect: Default::default(),
+            ssh_known_hosts_rect: Default::default(),
         };
         form.name_text_area.set_placeholder_text("must not be blank");
         form.name_text_area.set_placeholder_style(Style::default().fg(get_color(|t| &t.editor.warning)).dim());
         form.host_text_area.set_placeholder_text("127.0.0.1");
         form.port_text_area.set_placeholder_text("6379");
+        form.sentinel_master_name_text_area.set_placeholder_text("mymaster");
+        form.sentinel_addresses_text_area.set_placeholder_text("127.0.0.1:26379, 127.0.0.2:26379");
+        form.unix_socket_path_text_area.set_placeholder_text("/var/run/redis/redis.sock");
         form.username_text_area.set_placeholder_text("");
-        form.password_text_area.set_placeholder_text("");
+        form.password_text_area.set_placeholder_text("secret, env:VAR or cmd:<command>");
+        form.tls_ca_cert_text_area.set_placeholder_text("system trust store");
+        form.tls_client_cert_text_area.set_placeholder_text("");
+        form.tls_client_key_text_area.set_placeholder_text("");
         form.db_text_area.set_placeholder_text("0");
         form.ssh_host_text_area.set_placeholder_text("127.0.0.1");
         form.ssh_port_text_area.set_placeholder_text("22");
         form.ssh_username_text_area.set_placeholder_text("root");
-        form.ssh_password_text_area.set_placeholder_text("empty for ~/.ssh/id_rsa");
+        form.ssh_password_text_area.set_placeholder_text("empty for ~/.ssh/id_ed25519 or id_rsa, env:VAR or cmd:<command>");
+        form.ssh_key_path_text_area.set_placeholder_text("~/.ssh/id_ed25519");
+        form.ssh_key_passphrase_text_area.set_placeholder_text("");
+        form.ssh_agent_socket_text_area.set_placeholder_text("$SSH_AUTH_SOCK");
+        form.ssh_known_hosts_text_area.set_placeholder_text("~/.ssh/known_hosts");
 
         form.name_text_area.set_cursor_style(Style::default());
         form.host_text_area.set_cursor_style(Style::default());
         form.port_text_area.set_cursor_style(Style::default());
+        form.sentinel_master_name_text_area.set_cursor_style(Style::default());
+        form.sentinel_addresses_text_area.set_cursor_style(Style::default());
+        form.unix_socket_path_text_area.set_cursor_style(Style::default());
         form.username_text_area.set_cursor_style(Style::default());
         form.password_text_area.set_cursor_style(Style::default());
+        form.tls_ca_cert_text_area.set_cursor_style(Style::default());
+        form.tls_client_cert_text_area.set_cursor_style(Style::default());
+        form.tls_client_key_text_area.set_cursor_style(Style::default());
         form.db_text_area.set_cursor_style(Style::default());
         form.ssh_host_text_area.set_cursor_style(Style::default());
         form.ssh_port_text_area.set_cursor_style(Style::default());
         form.ssh_username_text_area.set_cursor_style(Style::default());
         form.ssh_password_text_area.set_cursor_style(Style::default());
+        form.ssh_key_path_text_area.set_cursor_style(Style::default());
+        form.ssh_key_passphrase_text_area.set_cursor_style(Style::default());
+        form.ssh_agent_socket_text_area.set_cursor_style(Style::default());
+        form.ssh_known_hosts_text_area.set_cursor_style(Style::default());
 
         form.name_text_area.insert_str(Uuid::new_v4().to_string());
         form.name_text_area.select_all();
         form.password_text_area.set_mask_char('•');
         form.ssh_password_text_area.set_mask_char('•');
+        form.ssh_key_passphrase_text_area.set_mask_char('•');
 
         form
     }
@@ -166,18 +378,45 @@ impl Form {
         form.name_text_area.insert_str(data.name.clone());
         form.host_text_area.insert_str(data.database.host.clone());
         form.port_text_area.insert_str(data.database.port.to_string());
-        form.enabled_authentication = data.database.password.is_some();
+        form.connection = if data.database.use_unix_socket {
+            Connection::UnixSocket
+        } else if data.database.use_sentinel {
+            Connection::Sentinel
+        } else {
+            Connection::Tcp
+        };
+        form.unix_socket_path_text_area.insert_str(data.database.unix_socket_path.clone().unwrap_or_default());
+        if let Some(ref sentinel) = data.database.sentinel {
+            form.sentinel_master_name_text_area.insert_str(sentinel.master_name.clone());
+            form.sentinel_addresses_text_area.insert_str(sentinel.addresses.join(", "));
+        }
+        form.enabled_authentication = data.database.password.is_some() || data.database.password_command.is_some();
         form.username_text_area.insert_str(data.database.username.clone().unwrap_or_default());
-        form.password_text_area.insert_str(data.database.password.clone().unwrap_or_default());
+        form.password_text_area.insert_str(password_text(data.database.password.clone(), data.database.password_command.clone()));
         form.use_tls = data.database.use_tls;
+        form.read_only = data.database.read_only;
+        if let Some(ref tls) = data.database.tls {
+            form.tls_ca_cert_text_area.insert_str(tls.ca_cert_path.clone().unwrap_or_default());
+            form.tls_client_cert_text_area.insert_str(tls.client_cert_path.clone().unwrap_or_default());
+            form.tls_client_key_text_area.insert_str(tls.client_key_path.clone().unwrap_or_default());
+            form.tls_insecure = tls.insecure;
+        }
         form.db_text_area.insert_str(data.db.clone());
         form.protocol = data.database.protocol.clone();
         form.use_ssh_tunnel = data.database.use_ssh_tunnel;
         if let Some(ref ssh_tunnel) = data.database.ssh_tunnel {
-            form.ssh_host_text_area.insert_str(ssh_tunnel.host.clone());
-            form.ssh_port_text_area.insert_str(ssh_tunnel.port.to_string());
-            form.ssh_username_text_area.insert_str(ssh_tunnel.username.clone());
-            form.ssh_password_text_area.insert_str(ssh_tunnel.password.clone());
+            form.ssh_known_hosts_text_area.insert_str(ssh_tunnel.known_hosts.clone().unwrap_or_default());
+            form.ssh_hops = ssh_tunnel.jump_hosts.clone();
+            form.ssh_hops.push(JumpHost {
+                host: ssh_tunnel.host.clone(),
+                port: ssh_tunnel.port,
+                username: ssh_tunnel.username.clone(),
+                password: ssh_tunnel.password.clone(),
+                password_command: ssh_tunnel.password_command.clone(),
+                auth: ssh_tunnel.auth.clone(),
+            });
+            form.ssh_hop_index = form.ssh_hops.len() - 1;
+            form.load_hop();
         }
         form
     }
@@ -194,22 +433,47 @@ impl Form {
     pub fn to_database(&self) -> Database {
         let host = self.host_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.host_text_area.placeholder_text().to_string());
         let port = self.port_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.port_text_area.placeholder_text().to_string()).parse::<u16>().unwrap_or(6379);
+        let use_sentinel = self.connection == Connection::Sentinel;
+        let use_unix_socket = self.connection == Connection::UnixSocket;
+        let unix_socket_path = if use_unix_socket {
+            Some(self.unix_socket_path_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.unix_socket_path_text_area.placeholder_text().to_string()))
+        } else { None };
+        let sentinel = if use_sentinel {
+            let master_name = self.sentinel_master_name_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.sentinel_master_name_text_area.placeholder_text().to_string());
+            let addresses = self.sentinel_addresses_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.sentinel_addresses_text_area.placeholder_text().to_string());
+            Some(Sentinel {
+                addresses: addresses.split(',').map(|x| x.trim().to_string()).filter(|x| !x.is_empty()).collect(),
+                master_name,
+            })
+        } else { None };
         let username = self.username_text_area.lines().get(0).cloned().filter(|x| !x.is_empty());
-        let password = self.password_text_area.lines().get(0).cloned().filter(|x| !x.is_empty());
+        let (password, password_command) = parse_password_text(self.password_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()));
         let use_tls = self.use_tls;
+        let tls = if use_tls {
+            Some(Tls {
+                ca_cert_path: self.tls_ca_cert_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+                client_cert_path: self.tls_client_cert_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+                client_key_path: self.tls_client_key_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+                insecure: self.tls_insecure,
+            })
+        } else { None };
         let db = self.db_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.db_text_area.placeholder_text().to_string()).parse::<u32>().unwrap_or(0);
         let protocol = self.protocol.clone();
         let use_ssh_tunnel = self.use_ssh_tunnel;
         let ssh_tunnel = if use_ssh_tunnel {
-            let ssh_host = self.ssh_host_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_host_text_area.placeholder_text().to_string());
-            let ssh_port = self.ssh_port_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_port_text_area.placeholder_text().to_string()).parse::<u16>().unwrap_or(6379);
-            let ssh_username = self.ssh_username_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_username_text_area.placeholder_text().to_string());
-            let ssh_password = self.ssh_password_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or_default();
+            let mut hops = self.ssh_hops.clone();
+            hops[self.ssh_hop_index] = self.current_hop();
+            // the last hop is the server that reaches redis, the others are jump hosts
+            let target = hops.pop().unwrap_or_default();
             Some(SshTunnel {
-                host: ssh_host,
-                port: ssh_port,
-                username: ssh_username,
-                password: ssh_password,
+                host: target.host,
+                port: target.port,
+                username: target.username,
+                password: target.password,
+                password_command: target.password_command,
+                auth: target.auth,
+                known_hosts: self.ssh_known_hosts_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+                jump_hosts: hops,
             })
         } else { None };
         Database {
@@ -217,11 +481,107 @@ impl Form {
             port,
             username,
             password,
+            password_command,
             use_tls,
             db,
             protocol,
             use_ssh_tunnel,
             ssh_tunnel,
+            use_sentinel,
+            sentinel,
+            use_unix_socket,
+            unix_socket_path,
+            tls,
+            read_only: self.read_only,
+        }
+    }
+
+    fn current_hop(&self) -> JumpHost {
+        let host = self.ssh_host_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_host_text_area.placeholder_text().to_string());
+        let port = self.ssh_port_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_port_text_area.placeholder_text().to_string()).parse::<u16>().unwrap_or(22);
+        let username = self.ssh_username_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_username_text_area.placeholder_text().to_string());
+        let (password, password_command) = parse_password_text(self.ssh_password_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()));
+        let auth = match self.ssh_auth {
+            SshAuthKind::Password => SshAuth::Password,
+            SshAuthKind::PrivateKey => SshAuth::PrivateKey {
+                path: self.ssh_key_path_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()).unwrap_or(self.ssh_key_path_text_area.placeholder_text().to_string()),
+                passphrase: self.ssh_key_passphrase_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+            },
+            SshAuthKind::Agent => SshAuth::Agent {
+                socket: self.ssh_agent_socket_text_area.lines().get(0).cloned().filter(|x| !x.is_empty()),
+            },
+        };
+        JumpHost {
+            host,
+            port,
+            username,
+            password: password.unwrap_or_default(),
+            password_command,
+            auth,
+        }
+    }
+
+    /// Fill the SSH fields with the selected hop.
+    fn load_hop(&mut self) {
+        let hop = self.ssh_hops.get(self.ssh_hop_index).cloned().unwrap_or_default();
+        set_text(&mut self.ssh_host_text_area, hop.host);
+        set_text(&mut self.ssh_port_text_area, if hop.port == 0 { String::new() } else { hop.port.to_string() });
+        set_text(&mut self.ssh_username_text_area, hop.username);
+        set_text(&mut self.ssh_password_text_area, password_text(Some(hop.password), hop.password_command));
+        set_text(&mut self.ssh_key_path_text_area, "");
+        set_text(&mut self.ssh_key_passphrase_text_area, "");
+        set_text(&mut self.ssh_agent_socket_text_area, "");
+        match hop.auth {
+            SshAuth::Password => self.ssh_auth = SshAuthKind::Password,
+            SshAuth::PrivateKey { path, passphrase } => {
+                self.ssh_auth = SshAuthKind::PrivateKey;
+                set_text(&mut self.ssh_key_path_text_area, path);
+                set_text(&mut self.ssh_key_passphrase_text_area, passphrase.unwrap_or_default());
+            }
+            SshAuth::Agent { socket } => {
+                self.ssh_auth = SshAuthKind::Agent;
+                set_text(&mut self.ssh_agent_socket_text_area, socket.unwrap_or_default());
+            }
+        }
+    }
+
+    fn switch_hop(&mut self, index: usize) {
+        self.ssh_hops[self.ssh_hop_index] = self.current_hop();
+        self.ssh_hop_index = index;
+        self.load_hop();
+    }
+
+    fn prev_hop(&mut self) {
+        let len = self.ssh_hops.len();
+        self.switch_hop((self.ssh_hop_index + len - 1) % len);
+    }
+
+    fn next_hop(&mut self) {
+        let len = self.ssh_hops.len();
+        self.switch_hop((self.ssh_hop_index + 1) % len);
+    }
+
+    /// Insert a new jump host in front of the selected hop.
+    fn add_hop(&mut self) {
+        self.ssh_hops[self.ssh_hop_index] = self.current_hop();
+        self.ssh_hops.insert(self.ssh_hop_index, JumpHost::default());
+        self.load_hop();
+    }
+
+    fn remove_hop(&mut self) {
+        if self.ssh_hops.len() > 1 {
+            self.ssh_hops.remove(self.ssh_hop_index);
+            self.ssh_hop_index = self.ssh_hop_index.min(self.ssh_hops.len() - 1);
+            self.load_hop();
+        }
+    }
+
+    fn hop_label(&self) -> String {
+        let len = self.ssh_hops.len();
+        if self.ssh_hop_index + 1 == len {
+            format!("◄ {}/{} Target ►", self.ssh_hop_index + 1, len)
+        } else {
+            format!("◄ {}/{} Jump ►", self.ssh_hop_index + 1, len)
         }
     }
 
@@ -235,17 +595,8 @@ impl Form {
     pub fn next(&mut self) {
         let tmp = self.editing + 1;
         self.editing = tmp % Editing::COUNT;
-        if !self.enabled_authentication {
-            let editing = self.current();
-            if editing == Editing::Username || editing == Editing::Password {
-                self.next();
-            }
-        }
-        if !self.use_ssh_tunnel {
-            let editing = self.current();
-            if editing == Editing::SshHost || editing == Editing::SshPort || editing == Editing::SshUsername || editing == Editing::SshPassword {
-                self.next();
-            }
+        if !self.is_visible(&self.current()) {
+            self.next();
         }
         self.change_editing();
     }
@@ -253,21 +604,29 @@ impl Form {
     pub fn prev(&mut self) {
         let tmp = self.editing + (Editing::COUNT - 1);
         self.editing = tmp % Editing::COUNT;
-        if !self.enabled_authentication {
-            let editing = self.current();
-            if editing == Editing::Username || editing == Editing::Password {
-                self.prev();
-            }
-        }
-        if !self.use_ssh_tunnel {
-            let editing = self.current();
-            if editing == Editing::SshHost || editing == Editing::SshPort || editing == Editing::SshUsername || editing == Editing::SshPassword {
-                self.prev();
-            }
+        if !self.is_visible(&self.current()) {
+            self.prev();
         }
         self.change_editing();
     }
 
+    fn is_visible(&self, editing: &Editing) -> bool {
+        match editing {
+            Editing::Host | Editing::Port => self.connection == Connection::Tcp,
+            Editing::SentinelMasterName | Editing::SentinelAddresses => self.connection == Connection::Sentinel,
+            Editing::UnixSocketPath => self.connection == Connection::UnixSocket,
+            Editing::UseTls => self.connection != Connection::UnixSocket,
+            Editing::TlsCaCert | Editing::TlsClientCert | Editing::TlsClientKey | Editing::TlsInsecure => self.use_tls && self.connection != Connection::UnixSocket,
+            Editing::UseSshTunnel => self.connection != Connection::UnixSocket,
+            Editing::Username | Editing::Password => self.enabled_authentication,
+            Editing::SshHop | Editing::SshHost | Editing::SshPort | Editing::SshUsername | Editing::SshAuth | Editing::SshKnownHosts => self.use_ssh_tunnel && self.connection != Connection::UnixSocket,
+            Editing::SshPassword => self.is_visible(&Editing::SshAuth) && self.ssh_auth == SshAuthKind::Password,
+            Editing::SshKeyPath | Editing::SshKeyPassphrase => self.is_visible(&Editing::SshAuth) && self.ssh_auth == SshAuthKind::PrivateKey,
+            Editing::SshAgentSocket => self.is_visible(&Editing::SshAuth) && self.ssh_auth == SshAuthKind::Agent,
+            _ => true,
+        }
+    }
+
     fn current(&self) -> Editing {
         Editing::iter().get(self.editing).unwrap()
     }
@@ -286,24 +645,66 @@ impl Form {
         self.name_text_area.set_cursor_style(Style::default());
         self.host_text_area.set_cursor_style(Style::default());
         self.port_text_area.set_cursor_style(Style::default());
+        self.sentinel_master_name_text_area.set_cursor_style(Style::default());
+        self.sentinel_addresses_text_area.set_cursor_style(Style::default());
+        self.unix_socket_path_text_area.set_cursor_style(Style::default());
         self.username_text_area.set_cursor_style(Style::default());
         self.password_text_area.set_cursor_style(Style::default());
+        self.tls_ca_cert_text_area.set_cursor_style(Style::default());
+        self.tls_client_cert_text_area.set_cursor_style(Style::default());
+        self.tls_client_key_text_area.set_cursor_style(Style::default());
         self.db_text_area.set_cursor_style(Style::default());
+        self.ssh_host_text_area.set_cursor_style(Style::default());
+        self.ssh_port_text_area.set_cursor_style(Style::default());
+        self.ssh_username_text_area.set_cursor_style(Style::default());
+        self.ssh_password_text_area.set_cursor_style(Style::default());
+        self.ssh_key_path_text_area.set_cursor_style(Style::default());
+        self.ssh_key_passphrase_text_area.set_cursor_style(Style::default());
+        self.ssh_agent_socket_text_area.set_cursor_style(Style::default());
+        self.ssh_known_hosts_text_area.set_cursor_style(Style::default());
 
         self.name_text_area.cancel_selection();
         self.host_text_area.cancel_selection();
         self.port_text_area.cancel_selection();
+        self.sentinel_master_name_text_area.cancel_selection();
+        self.sentinel_addresses_text_area.cancel_selection();
+        self.unix_socket_path_text_area.cancel_selection();
         self.username_text_area.cancel_selection();
         self.password_text_area.cancel_selection();
+        self.tls_ca_cert_text_area.cancel_selection();
+        self.tls_client_cert_text_area.cancel_selection();
+        self.tls_client_key_text_area.cancel_selection();
         self.db_text_area.cancel_selection();
+        self.ssh_host_text_area.cancel_selection();
+        self.ssh_port_text_area.cancel_selection();
+        self.ssh_username_text_area.cancel_selection();
+        self.ssh_password_text_area.cancel_selection();
+        self.ssh_key_path_text_area.cancel_selection();
+        self.ssh_key_passphrase_text_area.cancel_selection();
+        self.ssh_agent_socket_text_area.cancel_selection();
+        self.ssh_known_hosts_text_area.cancel_selection();
 
         match self.current() {
             Editing::Name => self.name_text_area.set_cursor_style(cursor_style()),
             Editing::Host => self.host_text_area.set_cursor_style(cursor_style()),
             Editing::Port => self.port_text_area.set_cursor_style(cursor_style()),
+            Editing::SentinelMasterName => self.sentinel_master_name_text_area.set_cursor_style(cursor_style()),
+            Editing::SentinelAddresses => self.sentinel_addresses_text_area.set_cursor_style(cursor_style()),
+            Editing::UnixSocketPath => self.unix_socket_path_text_area.set_cursor_style(cursor_style()),
             Editing::Username => self.username_text_area.set_cursor_style(cursor_style()),
             Editing::Password => self.password_text_area.set_cursor_style(cursor_style()),
+            Editing::TlsCaCert => self.tls_ca_cert_text_area.set_cursor_style(cursor_style()),
+            Editing::TlsClientCert => self.tls_client_cert_text_area.set_cursor_style(cursor_style()),
+            Editing::TlsClientKey => self.tls_client_key_text_area.set_cursor_style(cursor_style()),
             Editing::Db => self.db_text_area.set_cursor_style(cursor_style()),
+            Editing::SshHost => self.ssh_host_text_area.set_cursor_style(cursor_style()),
+            Editing::SshPort => self.ssh_port_text_area.set_cursor_style(cursor_style()),
+            Editing::SshUsername => self.ssh_username_text_area.set_cursor_style(cursor_style()),
+            Editing::SshPassword => self.ssh_password_text_area.set_cursor_style(cursor_style()),
+            Editing::SshKeyPath => self.ssh_key_path_text_area.set_cursor_style(cursor_style()),
+            Editing::SshKeyPassphrase => self.ssh_key_passphrase_text_area.set_cursor_style(cursor_style()),
+            Editing::SshAgentSocket => self.ssh_agent_socket_text_area.set_cursor_style(cursor_style()),
+            Editing::SshKnownHosts => self.ssh_known_hosts_text_area.set_cursor_style(cursor_style()),
             _ => {}
         }
     }
@@ -319,6 +720,49 @@ impl Form {
         self.name_rect = rc[1];
     }
 
+    fn render_connection(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::Connection);
+        let value = Span::raw(format!("◄ {} ►", self.connection)).style(key.style);
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.connection_rect = rc[1];
+    }
+
+    fn render_unix_socket_path(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::UnixSocketPath);
+        self.unix_socket_path_text_area.set_style(key.style);
+        let value = &self.unix_socket_path_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.unix_socket_path_rect = rc[1];
+    }
+
+    fn render_sentinel_master_name(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SentinelMasterName);
+        self.sentinel_master_name_text_area.set_style(key.style);
+        let value = &self.sentinel_master_name_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.sentinel_master_name_rect = rc[1];
+    }
+
+    fn render_sentinel_addresses(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SentinelAddresses);
+        self.sentinel_addresses_text_area.set_style(key.style);
+        let value = &self.sentinel_addresses_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.sentinel_addresses_rect = rc[1];
+    }
+
     fn render_host_port(&mut self, frame: &mut Frame, rect: Rect) {
         let horizontal = Layout::horizontal([Percentage(65), Percentage(35)]);
         let rc = horizontal.split(rect);
@@ -368,6 +812,7 @@ impl Form {
         let rc = horizontal.split(rect);
         let key = self.span(Editing::Password);
         self.password_text_area.set_style(key.style);
+        mask_secret(&mut self.password_text_area);
         let value = &self.password_text_area;
         frame.render_widget(key, rc[0]);
         frame.render_widget(value, rc[1]);
@@ -384,6 +829,49 @@ impl Form {
         self.tls_rect = rc[1];
     }
 
+    fn render_tls_ca_cert(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::TlsCaCert);
+        self.tls_ca_cert_text_area.set_style(key.style);
+        let value = &self.tls_ca_cert_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.tls_ca_cert_rect = rc[1];
+    }
+
+    fn render_tls_client_cert(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::TlsClientCert);
+        self.tls_client_cert_text_area.set_style(key.style);
+        let value = &self.tls_client_cert_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.tls_client_cert_rect = rc[1];
+    }
+
+    fn render_tls_client_key(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::TlsClientKey);
+        self.tls_client_key_text_area.set_style(key.style);
+        let value = &self.tls_client_key_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.tls_client_key_rect = rc[1];
+    }
+
+    fn render_tls_insecure(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::TlsInsecure);
+        let value = Span::raw(if self.tls_insecure { "◄ Yes ►" } else { "◄ No ►" }).style(key.style);
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.tls_insecure_rect = rc[1];
+    }
+
     fn render_db(&mut self, frame: &mut Frame, rect: Rect) {
         let horizontal = Layout::horizontal([Length(18), Fill(0)]);
         let rc = horizontal.split(rect);
@@ -405,6 +893,16 @@ impl Form {
         self.protocol_rect = rc[1];
     }
 
+    fn render_read_only(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::ReadOnly);
+        let value = Span::raw(if self.read_only { "◄ Yes ►" } else { "◄ No ►" }).style(key.style);
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.read_only_rect = rc[1];
+    }
+
     fn render_use_ssh_tunnel(&mut self, frame: &mut Frame, rect: Rect) {
         let horizontal = Layout::horizontal([Length(18), Fill(0)]);
         let rc = horizontal.split(rect);
@@ -415,6 +913,16 @@ impl Form {
         self.use_ssh_rect = rc[1];
     }
 
+    fn render_ssh_hop(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshHop);
+        let value = Span::raw(self.hop_label()).style(key.style);
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_hop_rect = rc[1];
+    }
+
     fn render_ssh_host_port(&mut self, frame: &mut Frame, rect: Rect) {
         let horizontal = Layout::horizontal([Percentage(65), Percentage(35)]);
         let rc = horizontal.split(rect);
@@ -449,11 +957,66 @@ impl Form {
         self.ssh_username_rect = rc[1];
     }
 
+    fn render_ssh_auth(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshAuth);
+        let value = Span::raw(format!("◄ {} ►", self.ssh_auth)).style(key.style);
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_auth_rect = rc[1];
+    }
+
+    fn render_ssh_key_path(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshKeyPath);
+        self.ssh_key_path_text_area.set_style(key.style);
+        let value = &self.ssh_key_path_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_key_path_rect = rc[1];
+    }
+
+    fn render_ssh_key_passphrase(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshKeyPassphrase);
+        self.ssh_key_passphrase_text_area.set_style(key.style);
+        let value = &self.ssh_key_passphrase_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_key_passphrase_rect = rc[1];
+    }
+
+    fn render_ssh_agent_socket(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshAgentSocket);
+        self.ssh_agent_socket_text_area.set_style(key.style);
+        let value = &self.ssh_agent_socket_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_agent_socket_rect = rc[1];
+    }
+
+    fn render_ssh_known_hosts(&mut self, frame: &mut Frame, rect: Rect) {
+        let horizontal = Layout::horizontal([Length(18), Fill(0)]);
+        let rc = horizontal.split(rect);
+        let key = self.span(Editing::SshKnownHosts);
+        self.ssh_known_hosts_text_area.set_style(key.style);
+        let value = &self.ssh_known_hosts_text_area;
+        frame.render_widget(key, rc[0]);
+        frame.render_widget(value, rc[1]);
+        self.ssh_known_hosts_rect = rc[1];
+    }
+
     fn render_ssh_password(&mut self, frame: &mut Frame, rect: Rect) {
         let horizontal = Layout::horizontal([Length(18), Fill(0)]);
         let rc = horizontal.split(rect);
         let key = self.span(Editing::SshPassword);
         self.ssh_password_text_area.set_style(key.style);
+        mask_secret(&mut self.ssh_password_text_area);
         let value = &self.ssh_password_text_area;
         frame.render_widget(key, rc[0]);
         frame.render_widget(value, rc[1]);
@@ -464,15 +1027,55 @@ impl Form {
 
 impl Renderable for Form {
     fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
-        let mut total_height = 9;
+        let mut rows: Vec<fn(&mut Self, &mut Frame, Rect)> = vec![];
+        rows.push(Self::render_name);
+        rows.push(Self::render_connection);
+        match self.connection {
+            Connection::Tcp => rows.push(Self::render_host_port),
+            Connection::Sentinel => {
+                rows.push(Self::render_sentinel_master_name);
+                rows.push(Self::render_sentinel_addresses);
+            }
+            Connection::UnixSocket => rows.push(Self::render_unix_socket_path),
+        }
+        rows.push(Self::render_enabled_auth);
         if self.enabled_authentication {
-            total_height += 2;
+            rows.push(Self::render_username);
+            rows.push(Self::render_password);
+        }
+        if self.is_visible(&Editing::UseTls) {
+            rows.push(Self::render_use_tls);
         }
-        let ssh_height = 3;
-        if self.use_ssh_tunnel {
-            total_height += ssh_height;
+        if self.is_visible(&Editing::TlsCaCert) {
+            rows.push(Self::render_tls_ca_cert);
+            rows.push(Self::render_tls_client_cert);
+            rows.push(Self::render_tls_client_key);
+            rows.push(Self::render_tls_insecure);
         }
-        let blank_length = (rect.height - total_height) / 2;
+        rows.push(Self::render_db);
+        rows.push(Self::render_protocol);
+        rows.push(Self::render_read_only);
+        if self.is_visible(&Editing::UseSshTunnel) {
+            rows.push(Self::render_use_ssh_tunnel);
+        }
+        if self.is_visible(&Editing::SshHost) {
+            rows.push(Self::render_ssh_hop);
+            rows.push(Self::render_ssh_host_port);
+            rows.push(Self::render_ssh_username);
+            rows.push(Self::render_ssh_auth);
+            match self.ssh_auth {
+                SshAuthKind::Password => rows.push(Self::render_ssh_password),
+                SshAuthKind::PrivateKey => {
+                    rows.push(Self::render_ssh_key_path);
+                    rows.push(Self::render_ssh_key_passphrase);
+                }
+                SshAuthKind::Agent => rows.push(Self::render_ssh_agent_socket),
+            }
+            rows.push(Self::render_ssh_known_hosts);
+        }
+
+        let total_height = rows.len() as u16 + 2;
+        let blank_length = rect.height.saturating_sub(total_height) / 2;
         let area = Layout::vertical([Length(blank_length), Length(total_height), Length(blank_length)]).split(rect)[1];
         let area = Layout::horizontal([Percentage(20), Percentage(60), Percentage(20)]).split(area)[1];
         // let area = centered_rect(50, 70, rect);
@@ -484,64 +1087,9 @@ impl Renderable for Form {
         let block_inner_area = block
             .inner(area);
         let block_inner_area = Layout::horizontal([Length(1), Fill(0), Length(1)]).split(block_inner_area)[1];
-        let inner_area_vertical = Layout::vertical([Fill(0), Length(ssh_height)]).split(block_inner_area);
-        let base_area = if self.use_ssh_tunnel {
-            inner_area_vertical[0]
-        } else {
-            block_inner_area
-        };
-
-        if !self.enabled_authentication {
-            let vertical = Layout::vertical([
-                Length(1), // name
-                Length(1), // host + port
-                Length(1), // enabled auth
-                Length(1), // tls
-                Length(1), // db
-                Length(1), // protocol
-                Length(1), // use ssh
-            ]);
-            let rc = vertical.split(base_area);
-            self.render_name(frame, rc[0]);
-            self.render_host_port(frame, rc[1]);
-            self.render_enabled_auth(frame, rc[2]);
-            self.render_use_tls(frame, rc[3]);
-            self.render_db(frame, rc[4]);
-            self.render_protocol(frame, rc[5]);
-            self.render_use_ssh_tunnel(frame, rc[6]);
-        } else {
-            let vertical = Layout::vertical([
-                Length(1), // name
-                Length(1), // host + port
-                Length(1), // enabled auth
-                Length(1), // username
-                Length(1), // password
-                Length(1), // tls
-                Length(1), // db
-                Length(1), // protocol
-                Length(1), // use ssh
-            ]);
-            let rc = vertical.split(base_area);
-            self.render_name(frame, rc[0]);
-            self.render_host_port(frame, rc[1]);
-            self.render_enabled_auth(frame, rc[2]);
-            self.render_username(frame, rc[3]);
-            self.render_password(frame, rc[4]);
-            self.render_use_tls(frame, rc[5]);
-            self.render_db(frame, rc[6]);
-            self.render_protocol(frame, rc[7]);
-            self.render_use_ssh_tunnel(frame, rc[8]);
-        }
-
-        if self.use_ssh_tunnel {
-            let rc = Layout::vertical([
-                Length(1), // host + port
-                Length(1), // username
-                Length(1), // password
-            ]).split(inner_area_vertical[1]);
-            self.render_ssh_host_port(frame, rc[0]);
-            self.render_ssh_username(frame, rc[1]);
-            self.render_ssh_password(frame, rc[2]);
+        let rc = Layout::vertical(vec![Length(1); rows.len()]).split(block_inner_area);
+        for (render, row_area) in rows.into_iter().zip(rc.iter()) {
+            render(self, frame, *row_area);
         }
         frame.render_widget(block, area);
         Ok(())
@@ -553,6 +1101,10 @@ impl Renderable for Form {
         elements.push(("BackTab", "Prev"));
 
         let editing = self.current();
+        if editing == Editing::SshHop {
+            elements.push(("a", "Add Jump Host"));
+            elements.push(("d", "Remove Hop"));
+        }
         if editing == Editing::Db || editing == Editing::Port {
             elements.push(("↑", "+10"));
             elements.push(("↓", "-10"));
@@ -581,13 +1133,23 @@ impl Listenable for Form {
             Editing::Name => Some(&mut self.name_text_area),
             Editing::Host => Some(&mut self.host_text_area),
             Editing::Port => Some(&mut self.port_text_area),
+            Editing::SentinelMasterName => Some(&mut self.sentinel_master_name_text_area),
+            Editing::SentinelAddresses => Some(&mut self.sentinel_addresses_text_area),
+            Editing::UnixSocketPath => Some(&mut self.unix_socket_path_text_area),
             Editing::Username => Some(&mut self.username_text_area),
             Editing::Password => Some(&mut self.password_text_area),
+            Editing::TlsCaCert => Some(&mut self.tls_ca_cert_text_area),
+            Editing::TlsClientCert => Some(&mut self.tls_client_cert_text_area),
+            Editing::TlsClientKey => Some(&mut self.tls_client_key_text_area),
             Editing::Db => Some(&mut self.db_text_area),
             Editing::SshHost => Some(&mut self.ssh_host_text_area),
             Editing::SshPort => Some(&mut self.ssh_port_text_area),
             Editing::SshUsername => Some(&mut self.ssh_username_text_area),
             Editing::SshPassword => Some(&mut self.ssh_password_text_area),
+            Editing::SshKeyPath => Some(&mut self.ssh_key_path_text_area),
+            Editing::SshKeyPassphrase => Some(&mut self.ssh_key_passphrase_text_area),
+            Editing::SshAgentSocket => Some(&mut self.ssh_agent_socket_text_area),
+            Editing::SshKnownHosts => Some(&mut self.ssh_known_hosts_text_area),
             _ => None,
         };
         if let Some(text_area) = editor {
@@ -656,27 +1218,39 @@ impl Listenable for Form {
                 KeyCode::Esc => {
                     return Ok(false);
                 },
+                KeyCode::Char('a') if editing == Editing::SshHop => self.add_hop(),
+                KeyCode::Char('d') if editing == Editing::SshHop => self.remove_hop(),
                 KeyCode::Char('h') | KeyCode::Left => {
                     match editing {
+                        Editing::Connection => self.connection = self.connection.prev(),
                         Editing::EnabledAuthentication => self.enabled_authentication = !self.enabled_authentication,
                         Editing::UseTls => self.use_tls = !self.use_tls,
+                        Editing::TlsInsecure => self.tls_insecure = !self.tls_insecure,
                         Editing::Protocol => self.protocol = match self.protocol {
                             Protocol::RESP2 => Protocol::RESP3,
                             Protocol::RESP3 => Protocol::RESP2,
                         },
+                        Editing::ReadOnly => self.read_only = !self.read_only,
                         Editing::UseSshTunnel => self.use_ssh_tunnel = !self.use_ssh_tunnel,
+                        Editing::SshAuth => self.ssh_auth = self.ssh_auth.prev(),
+                        Editing::SshHop => self.prev_hop(),
                         _ => {}
                     }
                 }
                 KeyCode::Char('l') | KeyCode::Right => {
                     match editing {
+                        Editing::Connection => self.connection = self.connection.next(),
                         Editing::EnabledAuthentication => self.enabled_authentication = !self.enabled_authentication,
                         Editing::UseTls => self.use_tls = !self.use_tls,
+                        Editing::TlsInsecure => self.tls_insecure = !self.tls_insecure,
                         Editing::Protocol => self.protocol = match self.protocol {
                             Protocol::RESP2 => Protocol::RESP3,
                             Protocol::RESP3 => Protocol::RESP2,
                         },
+                        Editing::ReadOnly => self.read_only = !self.read_only,
                         Editing::UseSshTunnel => self.use_ssh_tunnel = !self.use_ssh_tunnel,
+                        Editing::SshAuth => self.ssh_auth = self.ssh_auth.next(),
+                        Editing::SshHop => self.next_hop(),
                         _ => {}
                     }
                 }
@@ -691,11 +1265,39 @@ impl Listenable for Form {
             if mouse_event.within(&self.name_rect) {
                 self.select(Editing::Name);
             }
-            if mouse_event.within(&self.host_rect) {
-                self.select(Editing::Host);
-            }
-            if mouse_event.within(&self.port_rect) {
-                self.select(Editing::Port);
+            if mouse_event.within(&self.connection_rect) {
+                self.select(Editing::Connection);
+                // '◄'
+                if mouse_event.column == self.connection_rect.x {
+                    self.connection = self.connection.prev();
+                // '►'
+                } else if mouse_event.column == self.connection_rect.x + self.connection.to_string().len() as u16 + 3 {
+                    self.connection = self.connection.next();
+                }
+            } else {
+                match self.connection {
+                    Connection::Tcp => {
+                        if mouse_event.within(&self.host_rect) {
+                            self.select(Editing::Host);
+                        }
+                        if mouse_event.within(&self.port_rect) {
+                            self.select(Editing::Port);
+                        }
+                    }
+                    Connection::Sentinel => {
+                        if mouse_event.within(&self.sentinel_master_name_rect) {
+                            self.select(Editing::SentinelMasterName);
+                        }
+                        if mouse_event.within(&self.sentinel_addresses_rect) {
+                            self.select(Editing::SentinelAddresses);
+                        }
+                    }
+                    Connection::UnixSocket => {
+                        if mouse_event.within(&self.unix_socket_path_rect) {
+                            self.select(Editing::UnixSocketPath);
+                        }
+                    }
+                }
             }
             if mouse_event.within(&self.authentication_rect) {
                 self.select(Editing::EnabledAuthentication);
@@ -721,7 +1323,7 @@ impl Listenable for Form {
                     self.select(Editing::Password);
                 }
             }
-            if mouse_event.within(&self.tls_rect) {
+            if self.is_visible(&Editing::UseTls) && mouse_event.within(&self.tls_rect) {
                 self.select(Editing::UseTls);
                 if self.use_tls {
                     // '◄' || '►'
@@ -738,6 +1340,25 @@ impl Listenable for Form {
                 }
 
             }
+            if self.is_visible(&Editing::TlsCaCert) {
+                if mouse_event.within(&self.tls_ca_cert_rect) {
+                    self.select(Editing::TlsCaCert);
+                }
+                if mouse_event.within(&self.tls_client_cert_rect) {
+                    self.select(Editing::TlsClientCert);
+                }
+                if mouse_event.within(&self.tls_client_key_rect) {
+                    self.select(Editing::TlsClientKey);
+                }
+                if mouse_event.within(&self.tls_insecure_rect) {
+                    self.select(Editing::TlsInsecure);
+                    // '◄' || '►'
+                    if mouse_event.column == self.tls_insecure_rect.x
+                        || mouse_event.column == self.tls_insecure_rect.x + if self.tls_insecure { 6 } else { 5 } {
+                        self.tls_insecure = !self.tls_insecure;
+                    }
+                }
+            }
             if mouse_event.within(&self.db_rect) {
                 self.select(Editing::Db);
             }
@@ -760,7 +1381,15 @@ impl Listenable for Form {
                     }
                 }
             }
-            if mouse_event.within(&self.use_ssh_rect) {
+            if mouse_event.within(&self.read_only_rect) {
+                self.select(Editing::ReadOnly);
+                // '◄' || '►'
+                if mouse_event.column == self.read_only_rect.x
+                    || mouse_event.column == self.read_only_rect.x + if self.read_only { 6 } else { 5 } {
+                    self.read_only = !self.read_only;
+                }
+            }
+            if self.is_visible(&Editing::UseSshTunnel) && mouse_event.within(&self.use_ssh_rect) {
                 self.select(Editing::UseSshTunnel);
                 if self.use_ssh_tunnel {
                     // '◄' || '►'
@@ -776,7 +1405,7 @@ impl Listenable for Form {
                     }
                 }
             }
-            if self.use_ssh_tunnel {
+            if self.is_visible(&Editing::SshHost) {
                 if mouse_event.within(&self.ssh_host_rect) {
                     self.select(Editing::SshHost);
                 }
@@ -786,8 +1415,49 @@ impl Listenable for Form {
                 if mouse_event.within(&self.ssh_username_rect) {
                     self.select(Editing::SshUsername);
                 }
-                if mouse_event.within(&self.ssh_password_rect) {
-                    self.select(Editing::SshPassword);
+                if mouse_event.within(&self.ssh_hop_rect) {
+                    self.select(Editing::SshHop);
+                    // '◄'
+                    if mouse_event.column == self.ssh_hop_rect.x {
+                        self.prev_hop();
+                    // '►'
+                    } else if mouse_event.column == self.ssh_hop_rect.x + self.hop_label().chars().count() as u16 - 1 {
+                        self.next_hop();
+                    }
+                }
+                if mouse_event.within(&self.ssh_known_hosts_rect) {
+                    self.select(Editing::SshKnownHosts);
+                }
+                if mouse_event.within(&self.ssh_auth_rect) {
+                    self.select(Editing::SshAuth);
+                    // '◄'
+                    if mouse_event.column == self.ssh_auth_rect.x {
+                        self.ssh_auth = self.ssh_auth.prev();
+                    // '►'
+                    } else if mouse_event.column == self.ssh_auth_rect.x + self.ssh_auth.to_string().len() as u16 + 3 {
+                        self.ssh_auth = self.ssh_auth.next();
+                    }
+                } else {
+                    match self.ssh_auth {
+                        SshAuthKind::Password => {
+                            if mouse_event.within(&self.ssh_password_rect) {
+                                self.select(Editing::SshPassword);
+                            }
+                        }
+                        SshAuthKind::PrivateKey => {
+                            if mouse_event.within(&self.ssh_key_path_rect) {
+                                self.select(Editing::SshKeyPath);
+                            }
+                            if mouse_event.within(&self.ssh_key_passphrase_rect) {
+                                self.select(Editing::SshKeyPassphrase);
+                            }
+                        }
+                        SshAuthKind::Agent => {
+                            if mouse_event.within(&self.ssh_agent_socket_rect) {
+                                self.select(Editing::SshAgentSocket);
+                            }
+                        }
+                    }
                 }
             }
             return Ok(true);
@@ -0,0 +1,243 @@
+use crate::app::{Listenable, Renderable};
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use itertools::Itertools;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length};
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Text;
+use ratatui::widgets::{Block, BorderType, Borders, Cell, HighlightSpacing, Row, Table, TableState};
+use ratatui::{symbols, Frame};
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{redis_operations, select_db, spawn_redis_opt, Keyspace};
+use ratisui_core::theme::get_color;
+use ratisui_core::utils::clear_frame;
+
+/// Lists the logical databases of the active connection with their key statistics.
+pub struct DbSwitcher {
+    state: TableState,
+    items: Vec<Keyspace>,
+    current_db: u32,
+    loading: bool,
+    colors: TableColors,
+    data_sender: Sender<Vec<Keyspace>>,
+    data_receiver: Receiver<Vec<Keyspace>>,
+
+    table_rect: Rect,
+}
+
+impl DbSwitcher {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            state: TableState::default(),
+            items: vec![],
+            current_db: 0,
+            loading: false,
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            table_rect: Rect::default(),
+        }
+    }
+
+    /// Read `INFO keyspace` of the active connection again.
+    pub fn refresh(&mut self) -> Result<()> {
+        self.current_db = redis_operations()
+            .map(|operations| operations.database().db)
+            .unwrap_or(0);
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.keyspace().await {
+                Ok(keyspaces) => sender.send(keyspaces)?,
+                Err(e) => {
+                    sender.send(vec![])?;
+                    let _ = publish_msg(Message::error(format!("Failed to read keyspace: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })?;
+        Ok(())
+    }
+
+    fn update_items(&mut self, mut keyspaces: Vec<Keyspace>) {
+        // the current db is listed even if it is empty
+        if !keyspaces.iter().any(|keyspace| keyspace.db == self.current_db) {
+            keyspaces.push(Keyspace { db: self.current_db, ..Default::default() });
+        }
+        keyspaces.sort_by_key(|keyspace| keyspace.db);
+        let selected = keyspaces
+            .iter()
+            .position(|keyspace| keyspace.db == self.current_db);
+        self.items = keyspaces;
+        self.state.select(selected);
+        self.loading = false;
+    }
+
+    fn next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < self.items.len() => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => self.items.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn switch(&mut self) -> Result<()> {
+        if let Some(keyspace) = self.state.selected().and_then(|i| self.items.get(i)) {
+            if keyspace.db != self.current_db {
+                select_db(keyspace.db)?;
+                self.current_db = keyspace.db;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, area: Rect) {
+        let header_style = Style::default()
+            .bold()
+            .fg(self.colors.header_fg)
+            .bg(self.colors.header_bg);
+        let selected_style = Style::default().bg(get_color(|t| &t.server.highlight));
+
+        let header = ["", "DB", "Keys", "Expires", "Avg TTL"]
+            .into_iter()
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        let rows = self
+            .items
+            .iter()
+            .map(|keyspace| {
+                let avg_ttl = if keyspace.avg_ttl > 0 {
+                    chronoutil::RelativeDuration::seconds((keyspace.avg_ttl / 1000) as i64)
+                        .format_to_iso8601()
+                } else {
+                    "-".to_string()
+                };
+                [
+                    if keyspace.db == self.current_db { "*".to_string() } else { String::new() },
+                    keyspace.db.to_string(),
+                    keyspace.keys.to_string(),
+                    keyspace.expires.to_string(),
+                    avg_ttl,
+                ]
+                .into_iter()
+                .map(|content| Cell::from(Text::raw(content)))
+                .collect::<Row>()
+                .style(Style::new().fg(self.colors.row_fg))
+                .height(1)
+            })
+            .collect_vec();
+        let table = Table::new(
+            rows,
+            [Length(1), Length(4), Fill(1), Fill(1), Fill(1)],
+        )
+        .header(header)
+        .row_highlight_style(selected_style)
+        .highlight_symbol(Text::raw("➤ ").style(Style::default()))
+        .bg(self.colors.bg)
+        .column_spacing(1)
+        .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, area, &mut self.state);
+    }
+}
+
+impl Renderable for DbSwitcher {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        clear_frame(frame, rect);
+        let title = if self.loading { "Databases (loading...)" } else { "Databases" };
+        let block = Block::bordered()
+            .title(title)
+            .border_set(symbols::border::DOUBLE)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        let inner_block = block.inner(rect);
+        self.table_rect = inner_block;
+        frame.render_widget(block, rect);
+        self.render_table(frame, inner_block);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("↓/j", "Down"),
+            ("↑/k", "Up"),
+            ("Enter", "Choose"),
+            ("F5", "Refresh"),
+            ("Esc", "Close"),
+        ]
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(keyspaces) = self.data_receiver.try_recv() {
+            self.update_items(keyspaces);
+            needed = true;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for DbSwitcher {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if key_event.modifiers.is_empty() {
+            let accepted = match key_event.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.next();
+                    true
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.previous();
+                    true
+                }
+                KeyCode::Enter => {
+                    self.switch()?;
+                    true
+                }
+                KeyCode::F(5) => {
+                    self.refresh()?;
+                    true
+                }
+                _ => false,
+            };
+            return Ok(accepted);
+        }
+        Ok(false)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if self.table_rect.contains(mouse_event.as_position()) {
+            if mouse_event.is_scroll_down() {
+                self.next();
+            } else if mouse_event.is_scroll_up() {
+                self.previous();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
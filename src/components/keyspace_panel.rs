@@ -0,0 +1,495 @@
+use crate::app::{Listenable, Renderable, ServerPanel};
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{self, Fill, Length, Percentage};
+use ratatui::layout::{Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState};
+use ratatui::Frame;
+use ratisui_core::analyzer::{Aggregate, KeyStats, KeyspaceAnalyzer, KeyspaceReport};
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::configuration::save_report;
+use ratisui_core::constants::KEY_SEPARATOR;
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{spawn_redis_opt, ScanCursor};
+use ratisui_core::theme::get_color;
+use ratisui_core::utils::human_bytes;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const TOP_KEYS: usize = 100;
+const SCAN_COUNT: usize = 500;
+/// Keys inspected concurrently.
+const STATS_CONCURRENCY: usize = 50;
+const REPORT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Background scan of the whole keyspace, reporting the biggest keys and memory per prefix and type.
+pub struct KeyspacePanel {
+    report: Option<KeyspaceReport>,
+    scanned: u64,
+    total: u64,
+    analyzing: bool,
+    analyze_id: u64,
+    cancel: Option<Arc<AtomicBool>>,
+    started: Option<Instant>,
+    elapsed: Duration,
+    section: Section,
+    biggest_keys_state: TableState,
+    prefixes_state: TableState,
+    types_state: TableState,
+    without_expiry_state: TableState,
+    colors: TableColors,
+    data_sender: Sender<Data>,
+    data_receiver: Receiver<Data>,
+
+    section_rects: Vec<(Section, Rect)>,
+}
+
+struct Data {
+    analyze_id: u64,
+    report: KeyspaceReport,
+    scanned: u64,
+    total: u64,
+    done: bool,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Section {
+    BiggestKeys,
+    Prefixes,
+    Types,
+    WithoutExpiry,
+}
+
+impl Section {
+    const fn next(self) -> Self {
+        match self {
+            Self::BiggestKeys => Self::Prefixes,
+            Self::Prefixes => Self::Types,
+            Self::Types => Self::WithoutExpiry,
+            Self::WithoutExpiry => Self::BiggestKeys,
+        }
+    }
+
+    const fn previous(self) -> Self {
+        match self {
+            Self::BiggestKeys => Self::WithoutExpiry,
+            Self::Prefixes => Self::BiggestKeys,
+            Self::Types => Self::Prefixes,
+            Self::WithoutExpiry => Self::Types,
+        }
+    }
+}
+
+impl KeyspacePanel {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            report: None,
+            scanned: 0,
+            total: 0,
+            analyzing: false,
+            analyze_id: 0,
+            cancel: None,
+            started: None,
+            elapsed: Duration::ZERO,
+            section: Section::BiggestKeys,
+            biggest_keys_state: TableState::default(),
+            prefixes_state: TableState::default(),
+            types_state: TableState::default(),
+            without_expiry_state: TableState::default(),
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            section_rects: vec![],
+        }
+    }
+
+    fn cancel(&mut self) {
+        if let Some(cancel) = self.cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+        self.analyzing = false;
+    }
+
+    /// Scan every master and stream snapshots of the report until the scan ends or is cancelled.
+    fn analyze(&mut self) -> Result<()> {
+        self.cancel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.cancel = Some(cancel.clone());
+        self.analyze_id += 1;
+        self.analyzing = true;
+        self.started = Some(Instant::now());
+        self.scanned = 0;
+        self.total = 0;
+
+        let analyze_id = self.analyze_id;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            let total = operations.dbsize().await.unwrap_or(0);
+            let mut analyzer = KeyspaceAnalyzer::new(KEY_SEPARATOR, TOP_KEYS);
+            let mut cursor = ScanCursor::default();
+            let mut scanned = 0;
+            let mut last_report = Instant::now();
+            let mut result = Ok(());
+            while !cursor.is_finished() && !cancel.load(Ordering::Relaxed) {
+                let keys = match operations.scan_batch("*", SCAN_COUNT, &mut cursor).await {
+                    Ok(keys) => keys,
+                    Err(e) => {
+                        result = Err(e);
+                        break;
+                    }
+                };
+                scanned += keys.len() as u64;
+                for stats in operations.keys_stats(&keys, STATS_CONCURRENCY).await {
+                    analyzer.add(stats);
+                }
+                if last_report.elapsed() >= REPORT_INTERVAL {
+                    last_report = Instant::now();
+                    sender.send(Data {
+                        analyze_id,
+                        report: analyzer.report(),
+                        scanned,
+                        total,
+                        done: false,
+                    })?;
+                }
+            }
+            sender.send(Data {
+                analyze_id,
+                report: analyzer.report(),
+                scanned,
+                total,
+                done: true,
+            })?;
+            result
+        })
+    }
+
+    fn export(&self, csv: bool) -> Result<()> {
+        let Some(report) = &self.report else {
+            publish_msg(Message::warning("Nothing to export, press [r] to analyze the keyspace first"))?;
+            return Ok(());
+        };
+        let result = if csv {
+            save_report("keyspace", "csv", &report.to_csv())
+        } else {
+            serde_json::to_string_pretty(report)
+                .map_err(Error::from)
+                .and_then(|json| save_report("keyspace", "json", &json))
+        };
+        match result {
+            Ok(path) => publish_msg(Message::info(format!("Report saved to {}", path.display())))?,
+            Err(e) => publish_msg(Message::error(format!("Failed to export report: {}", e)))?,
+        }
+        Ok(())
+    }
+
+    fn section_len(&self, section: Section) -> usize {
+        self.report.as_ref().map_or(0, |report| match section {
+            Section::BiggestKeys => report.biggest_keys.len(),
+            Section::Prefixes => report.prefixes.len(),
+            Section::Types => report.types.len(),
+            Section::WithoutExpiry => report.biggest_keys_without_expiry.len(),
+        })
+    }
+
+    fn section_state(&mut self, section: Section) -> &mut TableState {
+        match section {
+            Section::BiggestKeys => &mut self.biggest_keys_state,
+            Section::Prefixes => &mut self.prefixes_state,
+            Section::Types => &mut self.types_state,
+            Section::WithoutExpiry => &mut self.without_expiry_state,
+        }
+    }
+
+    fn next(&mut self) {
+        let len = self.section_len(self.section);
+        if len == 0 {
+            return;
+        }
+        let state = self.section_state(self.section);
+        let i = match state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        let len = self.section_len(self.section);
+        if len == 0 {
+            return;
+        }
+        let state = self.section_state(self.section);
+        let i = match state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        state.select(Some(i));
+    }
+
+    fn render_status(&self, frame: &mut Frame, rect: Rect) {
+        if self.report.is_none() && !self.analyzing {
+            let line = Line::raw("Press [r] to scan the whole keyspace, every key is inspected with TYPE, MEMORY USAGE and TTL").dim();
+            frame.render_widget(line, rect);
+            return;
+        }
+        let mut spans = vec![];
+        let progress = if self.total > 0 {
+            format!(" ({:.0}%)", (self.scanned as f64 * 100.0 / self.total as f64).min(100.0))
+        } else {
+            String::new()
+        };
+        spans.push(Span::raw(format!("{}/{} keys{progress}", self.scanned, self.total)));
+        if let Some(report) = &self.report {
+            spans.push(Span::raw(format!(" | {} analyzed", human_bytes(report.memory))));
+        }
+        spans.push(Span::raw(format!(" | {}s", self.elapsed.as_secs())));
+        if self.analyzing {
+            spans.push(Span::raw(" | analyzing...").dim());
+        }
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn render_section(&mut self, frame: &mut Frame, rect: Rect, section: Section) {
+        let focused = self.section == section;
+        // only the rows in view are built, borders and header take three lines
+        let height = usize::from(rect.height.saturating_sub(3)).max(1);
+        let len = self.section_len(section);
+        let state = self.section_state(section);
+        let selected = state.selected().map(|selected| selected.min(len.saturating_sub(1)));
+        let mut offset = state.offset().min(len.saturating_sub(height));
+        if let Some(selected) = selected {
+            offset = offset.clamp((selected + 1).saturating_sub(height), selected);
+        }
+        *state.offset_mut() = offset;
+        let mut view_state = TableState::default().with_selected(selected.map(|selected| selected - offset));
+        let empty = KeyspaceReport::default();
+        let report = self.report.as_ref().unwrap_or(&empty);
+        let (title, header, widths, rows): (String, &[&str], Vec<Constraint>, Vec<Vec<String>>) = match section {
+            Section::BiggestKeys => (
+                format!("Biggest keys (top {TOP_KEYS})"),
+                &["Key", "Type", "Memory", "Length", "TTL"],
+                vec![Fill(1), Length(8), Length(9), Length(9), Length(9)],
+                report.biggest_keys.iter().skip(offset).take(height).map(key_row).collect(),
+            ),
+            Section::Prefixes => (
+                format!("Memory by prefix (\"{KEY_SEPARATOR}\")"),
+                &["Prefix", "Keys", "Memory", "Share"],
+                vec![Fill(1), Length(9), Length(9), Length(6)],
+                report
+                    .prefixes
+                    .iter()
+                    .skip(offset)
+                    .take(height)
+                    .map(|aggregate| aggregate_row(aggregate, report.memory))
+                    .collect(),
+            ),
+            Section::Types => (
+                "Types".to_string(),
+                &["Type", "Keys", "Memory", "Share"],
+                vec![Fill(1), Length(9), Length(9), Length(6)],
+                report
+                    .types
+                    .iter()
+                    .skip(offset)
+                    .take(height)
+                    .map(|aggregate| aggregate_row(aggregate, report.memory))
+                    .collect(),
+            ),
+            Section::WithoutExpiry => (
+                format!(
+                    "Without expiry: {} keys, {}",
+                    report.without_expiry.keys,
+                    human_bytes(report.without_expiry.memory)
+                ),
+                &["Key", "Type", "Memory", "Length", "TTL"],
+                vec![Fill(1), Length(8), Length(9), Length(9), Length(9)],
+                report.biggest_keys_without_expiry.iter().skip(offset).take(height).map(key_row).collect(),
+            ),
+        };
+        let header = header
+            .iter()
+            .map(|title| Cell::from(Text::raw(*title)))
+            .collect::<Row>()
+            .style(Style::default().bold().fg(self.colors.header_fg).bg(self.colors.header_bg))
+            .height(1);
+        let rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, cells)| {
+                let bg = if (offset + i) % 2 == 0 { self.colors.normal_row } else { self.colors.alt_row };
+                cells
+                    .into_iter()
+                    .map(|content| Cell::from(Text::raw(content)))
+                    .collect::<Row>()
+                    .style(Style::new().fg(self.colors.row_fg).bg(bg))
+                    .height(1)
+            })
+            .collect::<Vec<_>>();
+        let border = if focused {
+            get_color(|t| &t.border.highlight)
+        } else {
+            get_color(|t| &t.border.default)
+        };
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(title).border_style(border))
+            .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+            .highlight_symbol(Text::raw("➤ "))
+            .bg(self.colors.bg)
+            .column_spacing(1)
+            .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut view_state);
+    }
+}
+
+fn key_row(stats: &KeyStats) -> Vec<String> {
+    vec![
+        stats.key.clone(),
+        stats.key_type.clone(),
+        human_bytes(stats.memory),
+        stats.length.to_string(),
+        if stats.ttl == -1 { "-".to_string() } else { format!("{}s", stats.ttl) },
+    ]
+}
+
+fn aggregate_row(aggregate: &Aggregate, total_memory: u64) -> Vec<String> {
+    let share = if total_memory > 0 { aggregate.memory as f64 * 100.0 / total_memory as f64 } else { 0.0 };
+    vec![
+        aggregate.name.clone(),
+        aggregate.keys.to_string(),
+        human_bytes(aggregate.memory),
+        format!("{share:.1}%"),
+    ]
+}
+
+impl ServerPanel for KeyspacePanel {
+    /// Scanning the whole keyspace is expensive, it only starts on demand.
+    fn refresh(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn clear(&mut self) {
+        self.cancel();
+        self.report = None;
+        self.scanned = 0;
+        self.total = 0;
+        self.started = None;
+        self.elapsed = Duration::ZERO;
+        for section in [Section::BiggestKeys, Section::Prefixes, Section::Types, Section::WithoutExpiry] {
+            self.section_state(section).select(None);
+        }
+    }
+}
+
+impl Renderable for KeyspacePanel {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        let block = Block::bordered()
+            .title("Keyspace")
+            .border_style(get_color(|t| &t.border.default));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        let [status_area, top, bottom] = Layout::vertical([Length(1), Percentage(50), Fill(1)]).areas(inner);
+        let [biggest_keys_area, prefixes_area] = Layout::horizontal([Percentage(55), Fill(1)]).areas(top);
+        let [without_expiry_area, types_area] = Layout::horizontal([Percentage(55), Fill(1)]).areas(bottom);
+        self.section_rects = vec![
+            (Section::BiggestKeys, biggest_keys_area),
+            (Section::Prefixes, prefixes_area),
+            (Section::WithoutExpiry, without_expiry_area),
+            (Section::Types, types_area),
+        ];
+        self.render_status(frame, status_area);
+        for (section, area) in self.section_rects.clone() {
+            self.render_section(frame, area, section);
+        }
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        let mut elements = vec![];
+        if self.analyzing {
+            elements.push(("c", "Cancel"));
+        } else {
+            elements.push(("r", "Analyze"));
+        }
+        elements.push(("←/h", "Prev Section"));
+        elements.push(("→/l", "Next Section"));
+        elements.push(("↓/j", "Down"));
+        elements.push(("↑/k", "Up"));
+        elements.push(("e", "Export JSON"));
+        elements.push(("E", "Export CSV"));
+        elements
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(data) = self.data_receiver.try_recv() {
+            if data.analyze_id != self.analyze_id {
+                continue;
+            }
+            self.report = Some(data.report);
+            self.scanned = data.scanned;
+            self.total = data.total;
+            if data.done {
+                self.analyzing = false;
+                self.cancel = None;
+            }
+            needed = true;
+        }
+        if self.analyzing
+            && let Some(started) = self.started
+        {
+            self.elapsed = started.elapsed();
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for KeyspacePanel {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        match key_event.code {
+            KeyCode::Char('r') => self.analyze()?,
+            KeyCode::Char('c') if self.analyzing => self.cancel(),
+            KeyCode::Char('l') | KeyCode::Right => self.section = self.section.next(),
+            KeyCode::Char('h') | KeyCode::Left => self.section = self.section.previous(),
+            KeyCode::Char('j') | KeyCode::Down => self.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.previous(),
+            KeyCode::Char('e') => self.export(false)?,
+            KeyCode::Char('E') => self.export(true)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        let position = mouse_event.as_position();
+        let Some(section) = self
+            .section_rects
+            .iter()
+            .find(|(_, rect)| rect.contains(position))
+            .map(|(section, _)| *section)
+        else {
+            return Ok(false);
+        };
+        if mouse_event.is_left_down() {
+            self.section = section;
+        } else if mouse_event.is_scroll_down() {
+            self.section = section;
+            self.next();
+        } else if mouse_event.is_scroll_up() {
+            self.section = section;
+            self.previous();
+        }
+        Ok(true)
+    }
+}
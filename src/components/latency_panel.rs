@@ -0,0 +1,460 @@
+use crate::app::{Listenable, Renderable, ServerPanel};
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length, Percentage};
+use ratatui::layout::{Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::{
+    Axis, Block, Cell, Chart, Dataset, GraphType, HighlightSpacing, Paragraph, Row, Table, TableState, Wrap,
+};
+use ratatui::Frame;
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{is_read_only, spawn_redis_opt, Node};
+use ratisui_core::server::{format_unix_time, LatencyEvent};
+use ratisui_core::theme::get_color;
+use tui_textarea::TextArea;
+
+/// `LATENCY LATEST` with the history of the selected event and the doctor reports, of every master in cluster mode.
+pub struct LatencyPanel {
+    events: Vec<LatencyEvent>,
+    state: TableState,
+    /// Samples of the selected event.
+    history: Option<(LatencyEvent, Vec<(i64, u64)>)>,
+    /// `latency-monitor-threshold` of every master in milliseconds.
+    thresholds: Option<Vec<(Node, u64)>>,
+    threshold_text_area: Option<TextArea<'static>>,
+    latency_report: String,
+    memory_report: String,
+    show_memory_report: bool,
+    report_scroll: u16,
+    loading: bool,
+    colors: TableColors,
+    data_sender: Sender<Data>,
+    data_receiver: Receiver<Data>,
+
+    table_rect: Rect,
+    report_rect: Rect,
+}
+
+enum Data {
+    Latest(Vec<LatencyEvent>),
+    Thresholds(Vec<(Node, u64)>),
+    History(LatencyEvent, Vec<(i64, u64)>),
+    Reports(String, String),
+    Done,
+}
+
+impl LatencyPanel {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            events: vec![],
+            state: TableState::default(),
+            history: None,
+            thresholds: None,
+            threshold_text_area: None,
+            latency_report: String::new(),
+            memory_report: String::new(),
+            show_memory_report: false,
+            report_scroll: 0,
+            loading: false,
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            table_rect: Rect::default(),
+            report_rect: Rect::default(),
+        }
+    }
+
+    fn selected_event(&self) -> Option<&LatencyEvent> {
+        self.state.selected().and_then(|i| self.events.get(i))
+    }
+
+    /// The same event on the same node, timestamps and latencies change between refreshes.
+    fn same_event(a: &LatencyEvent, b: &LatencyEvent) -> bool {
+        a.event == b.event && a.node == b.node
+    }
+
+    /// Shared threshold of the masters, `None` when they differ.
+    fn threshold(&self) -> Option<u64> {
+        let thresholds = self.thresholds.as_ref()?;
+        let (_, first) = thresholds.first()?;
+        thresholds.iter().all(|(_, threshold)| threshold == first).then_some(*first)
+    }
+
+    fn load_history(&self) -> Result<()> {
+        let Some(event) = self.selected_event().cloned() else {
+            return Ok(());
+        };
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.latency_history(&event).await {
+                Ok(history) => sender.send(Data::History(event, history))?,
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to read latency history: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn set_threshold(&self, millis: u64) -> Result<()> {
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.set_latency_monitor_threshold(millis).await {
+                Ok(_) => {
+                    if let Ok(thresholds) = operations.latency_monitor_threshold().await {
+                        sender.send(Data::Thresholds(thresholds))?;
+                    }
+                    let message = if millis == 0 {
+                        "Latency monitor disabled".to_string()
+                    } else {
+                        format!("Latency monitor threshold set to {millis}ms")
+                    };
+                    let _ = publish_msg(Message::info(message));
+                }
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to set latency-monitor-threshold: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn apply_threshold(&mut self) -> Result<()> {
+        if let Some(text_area) = self.threshold_text_area.take() {
+            let input = text_area.lines().concat();
+            match input.trim().parse::<u64>() {
+                Ok(millis) => self.set_threshold(millis)?,
+                Err(_) => {
+                    let _ = publish_msg(Message::warning(format!("Invalid threshold: {input}")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn select(&mut self, i: Option<usize>) -> Result<()> {
+        if i != self.state.selected() {
+            self.state.select(i);
+            self.history = None;
+            self.load_history()?;
+        }
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        if self.events.is_empty() {
+            return Ok(());
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < self.events.len() => i + 1,
+            _ => 0,
+        };
+        self.select(Some(i))
+    }
+
+    fn previous(&mut self) -> Result<()> {
+        if self.events.is_empty() {
+            return Ok(());
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => self.events.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.select(Some(i))
+    }
+
+    fn render_status(&mut self, frame: &mut Frame, rect: Rect) {
+        if let Some(text_area) = &mut self.threshold_text_area {
+            let [label_area, input_area] = Layout::horizontal([Length(30), Fill(1)]).areas(rect);
+            frame.render_widget(Span::raw("latency-monitor-threshold ms: ").bold(), label_area);
+            text_area.set_cursor_line_style(Style::default());
+            frame.render_widget(&*text_area, input_area);
+            return;
+        }
+        let mut spans = vec![match (self.threshold(), &self.thresholds) {
+            (Some(0), _) => Span::raw("Latency monitor is off, press [t] to set a threshold")
+                .fg(get_color(|t| &t.toast.warn)),
+            (Some(threshold), _) => Span::raw(format!("latency-monitor-threshold {threshold}ms")),
+            (None, Some(thresholds)) if !thresholds.is_empty() => Span::raw(format!(
+                "latency-monitor-threshold differs: {}",
+                thresholds
+                    .iter()
+                    .map(|(node, threshold)| format!("{} {threshold}ms", node.addr))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+            .fg(get_color(|t| &t.toast.warn)),
+            (None, _) => Span::raw("latency-monitor-threshold ?"),
+        }];
+        spans.push(Span::raw(format!(" | {} events", self.events.len())));
+        if self.loading {
+            spans.push(Span::raw(" | loading...").dim());
+        }
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, rect: Rect) {
+        // standalone events all come from the connected server
+        let with_node = self.events.iter().any(|event| !event.node.id.is_empty());
+        let header = ["Event", "Node", "Time", "Latest", "Max"]
+            .into_iter()
+            .filter(|title| with_node || *title != "Node")
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(Style::default().bold().fg(self.colors.header_fg).bg(self.colors.header_bg))
+            .height(1);
+        let rows = self
+            .events
+            .iter()
+            .enumerate()
+            .map(|(i, event)| {
+                let bg = if i % 2 == 0 { self.colors.normal_row } else { self.colors.alt_row };
+                [
+                    Some(event.event.clone()),
+                    with_node.then(|| event.node.addr.clone()),
+                    Some(event.time()),
+                    Some(format!("{}ms", event.latest)),
+                    Some(format!("{}ms", event.max)),
+                ]
+                .into_iter()
+                .flatten()
+                .map(|content| Cell::from(Text::raw(content)))
+                .collect::<Row>()
+                .style(Style::new().fg(self.colors.row_fg).bg(bg))
+                .height(1)
+            })
+            .collect::<Vec<_>>();
+        let widths = if with_node {
+            vec![Fill(1), Length(21), Length(19), Length(8), Length(8)]
+        } else {
+            vec![Fill(1), Length(19), Length(8), Length(8)]
+        };
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title("Latest").border_style(get_color(|t| &t.border.default)))
+            .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+            .highlight_symbol(Text::raw("➤ "))
+            .bg(self.colors.bg)
+            .column_spacing(1)
+            .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut self.state);
+    }
+
+    fn render_history(&self, frame: &mut Frame, rect: Rect) {
+        let block = Block::bordered().border_style(get_color(|t| &t.border.default));
+        let Some((LatencyEvent { event, .. }, history)) = &self.history else {
+            let paragraph = Paragraph::new(Span::raw("Select an event to chart its history").dim())
+                .block(block.title("History"));
+            frame.render_widget(paragraph, rect);
+            return;
+        };
+        let data = history
+            .iter()
+            .map(|(timestamp, latency)| (*timestamp as f64, *latency as f64))
+            .collect::<Vec<_>>();
+        let x_min = history.first().map_or(0, |(timestamp, _)| *timestamp);
+        let x_max = history.last().map_or(0, |(timestamp, _)| *timestamp).max(x_min + 1);
+        let y_max = history.iter().map(|(_, latency)| *latency).max().unwrap_or(0).max(1) as f64 * 1.2;
+        let dataset = Dataset::default()
+            .name(event.clone())
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(get_color(|t| &t.raw.number)))
+            .data(&data);
+        let chart = Chart::new(vec![dataset])
+            .block(block.title(format!("History of {event} ({} samples)", history.len())))
+            .x_axis(
+                Axis::default()
+                    .bounds([x_min as f64, x_max as f64])
+                    .labels(vec![Span::raw(format_unix_time(x_min)), Span::raw(format_unix_time(x_max))]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, y_max])
+                    .labels(vec![Span::raw("0ms"), Span::raw(format!("{y_max:.0}ms"))]),
+            )
+            .hidden_legend_constraints((Percentage(50), Percentage(50)));
+        frame.render_widget(chart, rect);
+    }
+
+    fn render_report(&self, frame: &mut Frame, rect: Rect) {
+        let (title, report) = if self.show_memory_report {
+            ("MEMORY DOCTOR", &self.memory_report)
+        } else {
+            ("LATENCY DOCTOR", &self.latency_report)
+        };
+        let paragraph = Paragraph::new(Text::raw(report.as_str()))
+            .block(Block::bordered().title(title).border_style(get_color(|t| &t.border.default)))
+            .wrap(Wrap { trim: false })
+            .scroll((self.report_scroll, 0));
+        frame.render_widget(paragraph, rect);
+    }
+}
+
+impl ServerPanel for LatencyPanel {
+    fn refresh(&mut self) -> Result<()> {
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            let result = async {
+                sender.send(Data::Thresholds(operations.latency_monitor_threshold().await?))?;
+                sender.send(Data::Latest(operations.latency_latest().await?))?;
+                let (latency_report, memory_report) = operations.doctor_reports().await?;
+                sender.send(Data::Reports(latency_report, memory_report))?;
+                Ok::<(), Error>(())
+            }
+            .await;
+            if let Err(e) = result {
+                let _ = publish_msg(Message::error(format!("Failed to read latency: {}", e)));
+            }
+            sender.send(Data::Done)?;
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.state.select(None);
+        self.history = None;
+        self.thresholds = None;
+        self.latency_report.clear();
+        self.memory_report.clear();
+        self.report_scroll = 0;
+    }
+}
+
+impl Renderable for LatencyPanel {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        let block = Block::bordered()
+            .title("Latency")
+            .border_style(get_color(|t| &t.border.default));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        let [status_area, top_area, report_area] =
+            Layout::vertical([Length(1), Percentage(50), Fill(1)]).areas(inner);
+        let [table_area, history_area] = Layout::horizontal([Percentage(45), Fill(1)]).areas(top_area);
+        self.table_rect = table_area;
+        self.report_rect = report_area;
+        self.render_status(frame, status_area);
+        self.render_table(frame, table_area);
+        self.render_history(frame, history_area);
+        self.render_report(frame, report_area);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        if self.threshold_text_area.is_some() {
+            return vec![("Enter", "Apply"), ("Esc", "Cancel")];
+        }
+        vec![
+            ("↓/j", "Down"),
+            ("↑/k", "Up"),
+            ("m", if self.show_memory_report { "Latency Doctor" } else { "Memory Doctor" }),
+            ("PgDn/PgUp", "Scroll Report"),
+            ("t", "Threshold"),
+            ("F5", "Refresh"),
+        ]
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(data) = self.data_receiver.try_recv() {
+            match data {
+                Data::Latest(events) => {
+                    let selected = self.selected_event().cloned();
+                    self.events = events;
+                    let i = selected
+                        .and_then(|selected| self.events.iter().position(|event| Self::same_event(event, &selected)))
+                        .or_else(|| (!self.events.is_empty()).then_some(0));
+                    self.state.select(i);
+                    self.load_history()?;
+                }
+                Data::Thresholds(thresholds) => self.thresholds = Some(thresholds),
+                Data::History(event, history) => {
+                    if self.selected_event().is_some_and(|selected| Self::same_event(selected, &event)) {
+                        self.history = Some((event, history));
+                    }
+                }
+                Data::Reports(latency_report, memory_report) => {
+                    self.latency_report = latency_report;
+                    self.memory_report = memory_report;
+                }
+                Data::Done => self.loading = false,
+            }
+            needed = true;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for LatencyPanel {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if let Some(text_area) = &mut self.threshold_text_area {
+            match key_event.code {
+                KeyCode::Enter => self.apply_threshold()?,
+                KeyCode::Esc => self.threshold_text_area = None,
+                _ => {
+                    text_area.input(key_event);
+                }
+            }
+            return Ok(true);
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => self.next()?,
+            KeyCode::Char('k') | KeyCode::Up => self.previous()?,
+            KeyCode::Char('m') => {
+                self.show_memory_report = !self.show_memory_report;
+                self.report_scroll = 0;
+            }
+            KeyCode::PageDown => self.report_scroll = self.report_scroll.saturating_add(5),
+            KeyCode::PageUp => self.report_scroll = self.report_scroll.saturating_sub(5),
+            KeyCode::Char('t') => {
+                if is_read_only() {
+                    let _ = publish_msg(Message::warning("(read-only) CONFIG SET is rejected"));
+                } else {
+                    let mut text_area = TextArea::default();
+                    text_area.set_placeholder_text("milliseconds, 0 disables the monitor");
+                    if let Some(threshold) = self.threshold() {
+                        text_area.insert_str(threshold.to_string());
+                    }
+                    self.threshold_text_area = Some(text_area);
+                }
+            }
+            KeyCode::F(5) => self.refresh()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        let position = mouse_event.as_position();
+        if self.table_rect.contains(position) {
+            if mouse_event.is_scroll_down() {
+                self.next()?;
+            } else if mouse_event.is_scroll_up() {
+                self.previous()?;
+            }
+            return Ok(true);
+        }
+        if self.report_rect.contains(position) {
+            if mouse_event.is_scroll_down() {
+                self.report_scroll = self.report_scroll.saturating_add(3);
+            } else if mouse_event.is_scroll_up() {
+                self.report_scroll = self.report_scroll.saturating_sub(3);
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
@@ -20,6 +20,12 @@ pub mod stream_view;
 pub mod completion;
 pub mod ft_search_panel;
 pub mod cmd_viewer;
+pub mod db_switcher;
+pub mod slowlog_panel;
+pub mod clients_panel;
+pub mod latency_panel;
+pub mod keyspace_panel;
+pub mod config_panel;
 
 struct TableColors {
     // table background
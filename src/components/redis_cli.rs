@@ -9,6 +9,7 @@ use ratatui::Frame;
 use ratatui::crossterm::event::{KeyEvent, KeyEventKind};
 use ratatui::layout::Rect;
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use substring::Substring;
 
 pub struct RedisCli<'a> {
@@ -175,6 +176,131 @@ fn get_items(input: &str, cursor_x: usize) -> (Vec<CompletionItem>, String) {
     (commands, segment)
 }
 
+/// Inspection commands tagged `@admin`, `@dangerous` or `@scripting` that change nothing on the server.
+const READ_ONLY_COMMANDS: &[&str] = &[
+    "ACL DRYRUN",
+    "ACL GETUSER",
+    "ACL LIST",
+    "ACL LOG",
+    "ACL USERS",
+    "CLIENT LIST",
+    "CLUSTER COUNT-FAILURE-REPORTS",
+    "CLUSTER REPLICAS",
+    "CLUSTER SLAVES",
+    "CONFIG GET",
+    "EVALSHA_RO",
+    "EVAL_RO",
+    "FCALL_RO",
+    "FT.CONFIG GET",
+    "FT.CONFIG HELP",
+    "FT._LIST",
+    "FUNCTION DUMP",
+    "FUNCTION HELP",
+    "FUNCTION LIST",
+    "FUNCTION STATS",
+    "INFO",
+    "LASTSAVE",
+    "LATENCY DOCTOR",
+    "LATENCY GRAPH",
+    "LATENCY HISTOGRAM",
+    "LATENCY HISTORY",
+    "LATENCY LATEST",
+    "MODULE LIST",
+    "MONITOR",
+    "ROLE",
+    "SCRIPT EXISTS",
+    "SCRIPT HELP",
+    "SLOWLOG GET",
+    "SLOWLOG LEN",
+];
+
+/// Commands rejected in read-only mode: everything flagged `@write`, plus `@admin`, `@dangerous`
+/// and `@scripting` commands that are neither `@read` nor known to be inspection only,
+/// e.g. `SET`, `CONFIG SET`, `EVAL`, `SHUTDOWN`.
+static READ_ONLY_REJECTED_COMMANDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    let redis_cmd_json = include_str!("./redis-cmd.json");
+    let commands = serde_json::from_str::<Vec<Value>>(redis_cmd_json).unwrap_or_default();
+    commands
+        .iter()
+        .filter_map(|command| {
+            let name = command.get("command").and_then(Value::as_str)?.to_uppercase();
+            let acl = command.get("acl").and_then(Value::as_str)?;
+            let flags = acl.split(',').map(str::trim).collect::<Vec<_>>();
+            let rejected = flags.contains(&"@write")
+                || (flags.iter().any(|flag| matches!(*flag, "@admin" | "@dangerous" | "@scripting"))
+                    && !flags.contains(&"@read")
+                    && !READ_ONLY_COMMANDS.contains(&name.as_str()));
+            rejected.then_some(name)
+        })
+        .collect()
+});
+
+/// Whether the command line must be rejected in read-only mode, sub-commands are matched first.
+pub fn is_rejected_in_read_only(args: &[String]) -> bool {
+    let Some(first) = args.first() else {
+        return false;
+    };
+    if let Some(second) = args.get(1) {
+        let sub_command = format!("{} {}", first.to_uppercase(), second.to_uppercase());
+        if READ_ONLY_REJECTED_COMMANDS.contains(&sub_command) {
+            return true;
+        }
+    }
+    READ_ONLY_REJECTED_COMMANDS.contains(&first.to_uppercase())
+}
+
+/// Whether the command line starts with one of `patterns`, e.g. `KEYS *` or `CONFIG SET`.
+pub fn is_dangerous_command(args: &[String], patterns: &[String]) -> bool {
+    patterns.iter().any(|pattern| {
+        let tokens: Vec<&str> = pattern.split_whitespace().collect();
+        !tokens.is_empty()
+            && tokens.len() <= args.len()
+            && tokens
+                .iter()
+                .zip(args)
+                .all(|(token, arg)| token.eq_ignore_ascii_case(arg))
+    })
+}
+
+/// Where the keys of a command are, from its first argument when it is named `key`:
+/// `(offset, step)` with `step` set when the keys repeat until the end, e.g. `DEL key [key ...]`.
+static KEY_POSITIONS: Lazy<HashMap<String, (usize, Option<usize>)>> = Lazy::new(|| {
+    let redis_cmd_json = include_str!("./redis-cmd.json");
+    let commands = serde_json::from_str::<Vec<Value>>(redis_cmd_json).unwrap_or_default();
+    commands
+        .iter()
+        .filter_map(|command| {
+            let name = command.get("command").and_then(Value::as_str)?.to_uppercase();
+            let syntax = command.get("syntax").and_then(Value::as_str)?;
+            let first = command.get("arguments")?.as_array()?.first()?;
+            let arg_name = first.get("name").and_then(Value::as_str)?;
+            if arg_name.split_whitespace().next() != Some("key") {
+                return None;
+            }
+            let repeated = first.get("type").and_then(Value::as_str) == Some("many") && syntax.trim_end().ends_with("...]");
+            let step = repeated.then(|| arg_name.split_whitespace().count());
+            let offset = name.split_whitespace().count();
+            Some((name, (offset, step)))
+        })
+        .collect()
+});
+
+/// Keys of the command line as far as the command table tells, scripts and `numkeys` commands have none.
+pub fn command_keys(args: &[String]) -> Vec<&String> {
+    let Some(first) = args.first() else {
+        return vec![];
+    };
+    let sub_command = args
+        .get(1)
+        .map(|second| format!("{} {}", first.to_uppercase(), second.to_uppercase()))
+        .and_then(|sub_command| KEY_POSITIONS.get(&sub_command));
+    match sub_command.or_else(|| KEY_POSITIONS.get(&first.to_uppercase())) {
+        Some((offset, Some(step))) => args.iter().skip(*offset).step_by(*step).collect(),
+        Some((offset, None)) => args.get(*offset).into_iter().collect(),
+        None => vec![],
+    }
+}
+
 /// Redis Commands Completion Items Definition
 
 static COMMANDS: Lazy<Vec<CompletionItem>> = Lazy::new(|| {
@@ -335,3 +461,55 @@ fn value_to_string(value: &Value) -> String {
         String::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::components::redis_cli::{command_keys, is_rejected_in_read_only};
+
+    fn args(command: &str) -> Vec<String> {
+        command.split_whitespace().map(str::to_string).collect()
+    }
+
+    fn rejected(command: &str) -> bool {
+        is_rejected_in_read_only(&args(command))
+    }
+
+    fn keys(command: &str) -> Vec<String> {
+        command_keys(&args(command)).into_iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_read_only_rejection() {
+        for command in [
+            "set a 1",
+            "flushall",
+            "eval \"return 1\" 0",
+            "evalsha abc 0",
+            "fcall f 0",
+            "function load code",
+            "script flush",
+            "config set maxmemory 0",
+            "config rewrite",
+            "shutdown nosave",
+            "client kill id 3",
+            "debug sleep 1",
+            "slowlog reset",
+        ] {
+            assert!(rejected(command), "{command} should be rejected");
+        }
+        for command in ["get a", "keys *", "info memory", "config get *", "client list", "eval_ro \"return 1\" 0", "slowlog get"] {
+            assert!(!rejected(command), "{command} should be allowed");
+        }
+    }
+
+    #[test]
+    fn test_command_keys() {
+        assert_eq!(keys("get a"), ["a"]);
+        assert_eq!(keys("del a b c"), ["a", "b", "c"]);
+        assert_eq!(keys("mset a 1 b 2"), ["a", "b"]);
+        assert_eq!(keys("blpop a b 0"), ["a"]);
+        assert_eq!(keys("object encoding a"), ["a"]);
+        assert!(keys("ping").is_empty());
+        assert!(keys("eval \"return 1\" 1 a").is_empty());
+    }
+}
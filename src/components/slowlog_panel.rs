@@ -0,0 +1,408 @@
+use crate::app::{centered_rect, Listenable, Renderable, ServerPanel};
+use crate::components::popup::Popup;
+use crate::components::TableColors;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length};
+use ratatui::layout::{Alignment, Layout, Rect};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span, Text};
+use ratatui::widgets::block::Position;
+use ratatui::widgets::{Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Table, TableState};
+use ratatui::{symbols, Frame};
+use ratisui_core::bus::{publish_msg, Message};
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{is_read_only, spawn_redis_opt};
+use ratisui_core::server::{format_micros, parse_micros, SlowlogEntry};
+use ratisui_core::theme::get_color;
+use std::time::{Duration, Instant};
+use strum::Display;
+use tui_textarea::TextArea;
+
+const SLOWLOG_COUNT: usize = 128;
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// `SLOWLOG GET` of every master in one table.
+pub struct SlowlogPanel {
+    entries: Vec<SlowlogEntry>,
+    state: TableState,
+    sort: Sort,
+    descending: bool,
+    /// Entries faster than this, in microseconds, are hidden.
+    threshold: u64,
+    threshold_text_area: Option<TextArea<'static>>,
+    auto_refresh: bool,
+    last_refresh: Option<Instant>,
+    loading: bool,
+    confirm_reset: bool,
+    colors: TableColors,
+    data_sender: Sender<Vec<SlowlogEntry>>,
+    data_receiver: Receiver<Vec<SlowlogEntry>>,
+
+    table_rect: Rect,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Display)]
+enum Sort {
+    Time,
+    Duration,
+    Id,
+}
+
+impl Sort {
+    const fn next(self) -> Self {
+        match self {
+            Self::Time => Self::Duration,
+            Self::Duration => Self::Id,
+            Self::Id => Self::Time,
+        }
+    }
+}
+
+impl SlowlogPanel {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            entries: vec![],
+            state: TableState::default(),
+            sort: Sort::Time,
+            descending: true,
+            threshold: 0,
+            threshold_text_area: None,
+            auto_refresh: false,
+            last_refresh: None,
+            loading: false,
+            confirm_reset: false,
+            colors: TableColors::new(),
+            data_sender: tx,
+            data_receiver: rx,
+            table_rect: Rect::default(),
+        }
+    }
+
+    fn reset(&mut self) -> Result<()> {
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.slowlog_reset().await {
+                Ok(_) => {
+                    sender.send(vec![])?;
+                    let _ = publish_msg(Message::info("Slowlog reset"));
+                }
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to reset slowlog: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    /// Entries above the threshold in the chosen order.
+    fn visible_entries(&self) -> Vec<&SlowlogEntry> {
+        let mut entries = self
+            .entries
+            .iter()
+            .filter(|entry| entry.duration >= self.threshold)
+            .collect::<Vec<_>>();
+        match self.sort {
+            Sort::Time => entries.sort_by_key(|entry| (entry.timestamp, entry.id)),
+            Sort::Duration => entries.sort_by_key(|entry| entry.duration),
+            Sort::Id => entries.sort_by(|a, b| a.node.cmp(&b.node).then(a.id.cmp(&b.id))),
+        }
+        if self.descending {
+            entries.reverse();
+        }
+        entries
+    }
+
+    fn next(&mut self) {
+        let len = self.visible_entries().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn previous(&mut self) {
+        let len = self.visible_entries().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    fn select_first(&mut self) {
+        let selected = if self.visible_entries().is_empty() { None } else { Some(0) };
+        self.state.select(selected);
+    }
+
+    fn apply_threshold(&mut self) {
+        if let Some(text_area) = self.threshold_text_area.take() {
+            let input = text_area.lines().concat();
+            if input.trim().is_empty() {
+                self.threshold = 0;
+            } else if let Some(threshold) = parse_micros(&input) {
+                self.threshold = threshold;
+            } else {
+                let _ = publish_msg(Message::warning(format!("Invalid duration: {input}")));
+            }
+            self.select_first();
+        }
+    }
+
+    fn render_status(&mut self, frame: &mut Frame, rect: Rect) {
+        if let Some(text_area) = &mut self.threshold_text_area {
+            let [label_area, input_area] = Layout::horizontal([Length(12), Fill(1)]).areas(rect);
+            frame.render_widget(Span::raw("Slower than: ").bold(), label_area);
+            text_area.set_cursor_line_style(Style::default());
+            frame.render_widget(&*text_area, input_area);
+            return;
+        }
+        let visible = self.visible_entries().len();
+        let mut spans = vec![
+            Span::raw(format!("{visible}/{} entries", self.entries.len())),
+            Span::raw(format!(" | sort by {} {}", self.sort, if self.descending { "↓" } else { "↑" })),
+        ];
+        if self.threshold > 0 {
+            spans.push(Span::raw(format!(" | slower than {}", format_micros(self.threshold))));
+        }
+        if self.auto_refresh {
+            spans.push(Span::raw(format!(" | auto refresh {}s", REFRESH_INTERVAL.as_secs())));
+        }
+        if self.loading {
+            spans.push(Span::raw(" | loading...").dim());
+        }
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, rect: Rect) {
+        let entries = self.visible_entries();
+        let show_node = entries.first().is_some_and(|first| entries.iter().any(|entry| entry.node != first.node));
+        let mut titles = vec!["Id", "Time", "Duration", "Command", "Client", "Name"];
+        let mut widths = vec![Length(6), Length(19), Length(10), Fill(3), Length(21), Fill(1)];
+        if show_node {
+            titles.insert(0, "Node");
+            widths.insert(0, Length(21));
+        }
+        let header = titles
+            .into_iter()
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(Style::default().bold().fg(self.colors.header_fg).bg(self.colors.header_bg))
+            .height(1);
+        let rows = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let mut cells = vec![
+                    entry.id.to_string(),
+                    entry.time(),
+                    format_micros(entry.duration),
+                    entry.command_line(),
+                    entry.client_addr.clone(),
+                    entry.client_name.clone(),
+                ];
+                if show_node {
+                    cells.insert(0, entry.node.clone());
+                }
+                let bg = if i % 2 == 0 { self.colors.normal_row } else { self.colors.alt_row };
+                cells
+                    .into_iter()
+                    .map(|content| Cell::from(Text::raw(content)))
+                    .collect::<Row>()
+                    .style(Style::new().fg(self.colors.row_fg).bg(bg))
+                    .height(1)
+            })
+            .collect::<Vec<_>>();
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+            .highlight_symbol(Text::raw("➤ "))
+            .bg(self.colors.bg)
+            .column_spacing(1)
+            .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut self.state);
+    }
+
+    fn render_confirm_popup(&self, frame: &mut Frame, rect: Rect) {
+        if self.confirm_reset {
+            let popup_area = centered_rect(40, 20, rect);
+            let mut text = Text::default();
+            text.push_line(Line::raw("SLOWLOG RESET").alignment(Alignment::Center).bold());
+            text.push_line(Line::default());
+            text.push_line(Line::raw("Entries of every master will be dropped.").alignment(Alignment::Center));
+            text.push_line(Line::raw("Are you sure?").alignment(Alignment::Center).bold());
+            let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+            let popup = Popup::new(paragraph)
+                .title(String::from(" [Enter] Reset | [Esc] Cancel "))
+                .title_position(Position::Bottom)
+                .borders(Borders::ALL)
+                .border_set(symbols::border::DOUBLE)
+                .border_style(Style::default().fg(get_color(|t| &t.toast.warn)));
+            frame.render_widget(popup, popup_area);
+        }
+    }
+}
+
+impl ServerPanel for SlowlogPanel {
+    fn refresh(&mut self) -> Result<()> {
+        self.last_refresh = Some(Instant::now());
+        self.loading = true;
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.slowlog(SLOWLOG_COUNT).await {
+                Ok(entries) => sender.send(entries)?,
+                Err(e) => {
+                    sender.send(vec![])?;
+                    let _ = publish_msg(Message::error(format!("Failed to read slowlog: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.state.select(None);
+        self.last_refresh = None;
+    }
+}
+
+impl Renderable for SlowlogPanel {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()> {
+        let block = Block::bordered()
+            .title("Slowlog")
+            .border_style(get_color(|t| &t.border.default));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        let [status_area, table_area] = Layout::vertical([Length(1), Fill(1)]).areas(inner);
+        self.table_rect = table_area;
+        self.render_status(frame, status_area);
+        self.render_table(frame, table_area);
+        self.render_confirm_popup(frame, rect);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        if self.confirm_reset {
+            return vec![("Enter", "Reset"), ("Esc", "Cancel")];
+        }
+        if self.threshold_text_area.is_some() {
+            return vec![("Enter", "Apply"), ("Esc", "Cancel")];
+        }
+        vec![
+            ("↓/j", "Down"),
+            ("↑/k", "Up"),
+            ("s", "Sort"),
+            ("S", "Reverse"),
+            ("f", "Threshold"),
+            ("a", "Auto Refresh"),
+            ("x", "Reset"),
+            ("F5", "Refresh"),
+        ]
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(entries) = self.data_receiver.try_recv() {
+            self.entries = entries;
+            self.loading = false;
+            let len = self.visible_entries().len();
+            let selected = match self.state.selected() {
+                _ if len == 0 => None,
+                Some(i) => Some(i.min(len - 1)),
+                None => Some(0),
+            };
+            self.state.select(selected);
+            needed = true;
+        }
+        if self.auto_refresh
+            && !self.loading
+            && self.last_refresh.is_none_or(|last_refresh| last_refresh.elapsed() >= REFRESH_INTERVAL)
+        {
+            self.refresh()?;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for SlowlogPanel {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if self.confirm_reset {
+            match key_event.code {
+                KeyCode::Enter => {
+                    self.confirm_reset = false;
+                    self.reset()?;
+                }
+                KeyCode::Esc => self.confirm_reset = false,
+                _ => {}
+            }
+            return Ok(true);
+        }
+        if let Some(text_area) = &mut self.threshold_text_area {
+            match key_event.code {
+                KeyCode::Enter => self.apply_threshold(),
+                KeyCode::Esc => self.threshold_text_area = None,
+                _ => {
+                    text_area.input(key_event);
+                }
+            }
+            return Ok(true);
+        }
+        match key_event.code {
+            KeyCode::Char('j') | KeyCode::Down => self.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.previous(),
+            KeyCode::Char('s') => {
+                self.sort = self.sort.next();
+                self.select_first();
+            }
+            KeyCode::Char('S') => {
+                self.descending = !self.descending;
+                self.select_first();
+            }
+            KeyCode::Char('f') => {
+                let mut text_area = TextArea::default();
+                text_area.set_placeholder_text("e.g. 10ms, 500us, 1s");
+                if self.threshold > 0 {
+                    text_area.insert_str(format_micros(self.threshold));
+                }
+                self.threshold_text_area = Some(text_area);
+            }
+            KeyCode::Char('a') => self.auto_refresh = !self.auto_refresh,
+            KeyCode::Char('x') => {
+                if is_read_only() {
+                    let _ = publish_msg(Message::warning("(read-only) SLOWLOG RESET is rejected"));
+                } else {
+                    self.confirm_reset = true;
+                }
+            }
+            KeyCode::F(5) => self.refresh()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if self.table_rect.contains(mouse_event.as_position()) {
+            if mouse_event.is_scroll_down() {
+                self.next();
+            } else if mouse_event.is_scroll_up() {
+                self.previous();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
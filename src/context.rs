@@ -1,23 +1,31 @@
+use std::collections::HashMap;
+use std::mem;
 use std::sync::Arc;
 use crate::app::{centered_rect, AppEvent, Listenable, Renderable, TabImplementation};
 use crate::components::cmd_viewer::CmdViewer;
+use crate::components::db_switcher::DbSwitcher;
 use crate::components::fps::FpsCalculator;
+use crate::components::popup::Popup;
 use crate::components::servers::ServerList;
 use crate::tabs::cli::CliTab;
 use crate::tabs::explorer::ExplorerTab;
 use crate::tabs::logger::LoggerTab;
+use crate::tabs::scripts::ScriptsTab;
+use crate::tabs::dashboard::DashboardTab;
 use anyhow::{anyhow, Result};
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
 use ratatui::layout::Constraint::{Fill, Length, Max, Min};
 use ratatui::layout::{Alignment, Layout, Rect};
 use ratatui::prelude::{Color, Span, Style, Stylize, Text};
 use ratatui::text::Line;
+use ratatui::widgets::block::Position;
 use ratatui::widgets::{Block, Borders, Paragraph, Tabs, WidgetRef, Wrap};
 use ratatui::{symbols, Frame};
-use ratisui_core::bus::{publish_msg, GlobalEvent, Kind, Message};
+use ratisui_core::bus::{publish_msg, ConnectionStatus, GlobalEvent, Kind, Message};
 use ratisui_core::configuration::{load_database_configuration, load_theme_configuration, Configuration, Databases};
 use ratisui_core::marcos::KeyAsserter;
-use ratisui_core::redis_opt::{redis_operations, switch_client};
+use ratisui_core::redis_opt::{activate_workspace, close_workspace, force_read_only, redis_operations, switch_client, workspaces};
+use ratisui_core::ssh_tunnel::{try_recv_host_key_prompt, HostKeyPrompt};
 use ratisui_core::theme::get_color;
 use ratisui_core::utils::{clear_frame, none_match};
 use std::time::Instant;
@@ -30,17 +38,28 @@ use crate::components::app_configuration_editor::Options;
 
 pub struct Context {
     show_server_switcher: bool,
+    show_db_switcher: bool,
     show_app_options: bool,
     current_tab: CurrentTab,
     current_tab_index: usize,
     explorer_tab: ExplorerTab,
     cli_tab: CliTab,
+    scripts_tab: ScriptsTab,
+    dashboard_tab: DashboardTab,
     logger_tab: LoggerTab,
     server_list: ServerList,
+    db_switcher: DbSwitcher,
     app_options: Options,
     title: String,
+    connection_status: HashMap<String, ConnectionStatus>,
+    /// Name of the workspace the explorer and cli tabs belong to.
+    workspace: Option<String>,
+    /// Explorer and cli state of the background workspaces.
+    background_tabs: HashMap<String, (ExplorerTab, CliTab)>,
     show_cmd_viewer: bool,
+    host_key_prompt: Option<HostKeyPrompt>,
     initial_configuration: Arc<Configuration>,
+    initial_arguments: Arc<AppArguments>,
     pub toast: Option<Message>,
     pub fps_calculator: FpsCalculator,
 
@@ -51,6 +70,8 @@ pub struct Context {
 enum CurrentTab {
     Explorer,
     Cli,
+    Scripts,
+    Dashboard,
     Logger,
 }
 
@@ -58,18 +79,27 @@ impl Context {
     pub fn new() -> Self {
         Self {
             show_server_switcher: false,
+            show_db_switcher: false,
             show_app_options: false,
             current_tab: CurrentTab::Explorer,
             current_tab_index: 0,
             explorer_tab: ExplorerTab::new(),
             cli_tab: CliTab::new(),
+            scripts_tab: ScriptsTab::new(),
+            dashboard_tab: DashboardTab::new(),
             logger_tab: LoggerTab::new(),
             // server_list: ServerList::new(&databases),
             server_list: ServerList::new(&Databases::empty()),
+            db_switcher: DbSwitcher::new(),
             app_options: Options::default(),
             title: "redis ver: ?.?.?".to_string(),
+            connection_status: HashMap::new(),
+            workspace: None,
+            background_tabs: HashMap::new(),
             show_cmd_viewer: false,
+            host_key_prompt: None,
             initial_configuration: Arc::new(Configuration::default()),
+            initial_arguments: Arc::new(AppArguments::default()),
             toast: None,
             fps_calculator: FpsCalculator::default(),
             tab_area: Rect::default(),
@@ -80,6 +110,8 @@ impl Context {
         match self.current_tab {
             CurrentTab::Explorer => &self.explorer_tab,
             CurrentTab::Cli => &self.cli_tab,
+            CurrentTab::Scripts => &self.scripts_tab,
+            CurrentTab::Dashboard => &self.dashboard_tab,
             CurrentTab::Logger => &self.logger_tab,
         }
     }
@@ -88,12 +120,14 @@ impl Context {
         match self.current_tab {
             CurrentTab::Explorer => &mut self.explorer_tab,
             CurrentTab::Cli => &mut self.cli_tab,
+            CurrentTab::Scripts => &mut self.scripts_tab,
+            CurrentTab::Dashboard => &mut self.dashboard_tab,
             CurrentTab::Logger => &mut self.logger_tab,
         }
     }
 
     pub fn get_all_tabs(&self) -> Vec<&dyn TabImplementation> {
-        vec![&self.explorer_tab, &self.cli_tab, &self.logger_tab]
+        vec![&self.explorer_tab, &self.cli_tab, &self.scripts_tab, &self.dashboard_tab, &self.logger_tab]
     }
 
     fn next_tab(&mut self) {
@@ -118,6 +152,87 @@ impl Context {
         Ok(())
     }
 
+    /// Swap in the explorer and cli tabs of the active workspace, keeping the previous ones aside.
+    /// Returns true when the tabs of a background workspace were restored, they are still
+    /// attached to that connection and keep their state.
+    fn sync_workspace(&mut self) -> Result<bool> {
+        let name = redis_operations().map(|operations| operations.name);
+        if self.workspace.is_none() {
+            // the first connection adopts the initial tabs
+            self.workspace = name;
+            return Ok(false);
+        }
+        if name == self.workspace {
+            return Ok(false);
+        }
+        let (explorer_tab, cli_tab, restored) = match name.as_ref().and_then(|n| self.background_tabs.remove(n)) {
+            Some((explorer_tab, cli_tab)) => (explorer_tab, cli_tab, true),
+            None => {
+                let (explorer_tab, cli_tab) = self.new_workspace_tabs()?;
+                (explorer_tab, cli_tab, false)
+            }
+        };
+        let previous_explorer_tab = mem::replace(&mut self.explorer_tab, explorer_tab);
+        let previous_cli_tab = mem::replace(&mut self.cli_tab, cli_tab);
+        if let Some(previous) = self.workspace.take() {
+            self.background_tabs.insert(previous, (previous_explorer_tab, previous_cli_tab));
+        }
+        let open = workspaces();
+        self.background_tabs.retain(|n, _| open.contains(n));
+        self.connection_status.retain(|n, _| open.contains(n));
+        self.workspace = name;
+        Ok(restored)
+    }
+
+    fn new_workspace_tabs(&self) -> Result<(ExplorerTab, CliTab)> {
+        let init_config = AppEvent::InitConfig(
+            Arc::clone(&self.initial_configuration),
+            Arc::clone(&self.initial_arguments),
+        );
+        let mut explorer_tab = ExplorerTab::new();
+        let mut cli_tab = CliTab::new();
+        explorer_tab.on_app_event(init_config.clone())?;
+        cli_tab.on_app_event(init_config)?;
+        Ok((explorer_tab, cli_tab))
+    }
+
+    fn next_workspace(&mut self) -> Result<()> {
+        // the active workspace is listed first, the next one is the oldest in the background
+        if let Some(name) = workspaces().get(1) {
+            activate_workspace(name)?;
+        }
+        Ok(())
+    }
+
+    /// Names of the open workspaces, the active one first.
+    fn workspaces_line(&self) -> Line<'static> {
+        let names = workspaces();
+        let mut spans = vec![];
+        for (i, name) in names.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(" | "));
+            }
+            if i == 0 {
+                spans.push(Span::raw(name).bold().underlined());
+            } else {
+                spans.push(Span::raw(name).dim());
+            }
+        }
+        Line::from(spans)
+    }
+
+    fn render_connection_status(&self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if let Some(status) = self.workspace.as_ref().and_then(|n| self.connection_status.get(n)).copied() {
+            let color = match status {
+                ConnectionStatus::Connected => get_color(|t| &t.toast.info),
+                ConnectionStatus::Reconnecting => get_color(|t| &t.toast.warn),
+                ConnectionStatus::Down => get_color(|t| &t.toast.error),
+            };
+            frame.render_widget(Line::from(format!("● {status}")).fg(color).bold(), area);
+        }
+        Ok(())
+    }
+
     fn render_title(&self, frame: &mut Frame, area: Rect) -> Result<()> {
         frame.render_widget(self.title.clone().bold(), area);
         Ok(())
@@ -178,6 +293,8 @@ impl Context {
         match self.current_tab {
             CurrentTab::Explorer => self.explorer_tab.render_frame(frame, area),
             CurrentTab::Cli => self.cli_tab.render_frame(frame, area),
+            CurrentTab::Scripts => self.scripts_tab.render_frame(frame, area),
+            CurrentTab::Dashboard => self.dashboard_tab.render_frame(frame, area),
             CurrentTab::Logger => self.logger_tab.render_frame(frame, area),
         }
     }
@@ -212,6 +329,14 @@ impl Context {
         Ok(())
     }
 
+    fn render_db_switcher(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if self.show_db_switcher {
+            let popup_area = centered_rect(50, 30, area);
+            self.db_switcher.render_frame(frame, popup_area)?;
+        }
+        Ok(())
+    }
+
     fn render_app_options(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         if self.show_app_options {
             let popup_area = centered_rect(74, 30, area);
@@ -220,6 +345,49 @@ impl Context {
         Ok(())
     }
 
+    fn render_host_key_prompt(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
+        if let Some(prompt) = &self.host_key_prompt {
+            let popup_area = centered_rect(50, 25, area);
+            let mut text = Text::default();
+            text.push_line(
+                Line::raw(format!("{}:{}", prompt.host, prompt.port))
+                    .alignment(Alignment::Center)
+                    .underlined(),
+            );
+            text.push_line(Line::default());
+            text.push_line(
+                Line::raw("The authenticity of this host can't be established.")
+                    .alignment(Alignment::Center),
+            );
+            text.push_line(
+                Line::raw(format!("{} key fingerprint is", prompt.algorithm))
+                    .alignment(Alignment::Center),
+            );
+            text.push_line(
+                Line::raw(prompt.fingerprint.clone())
+                    .alignment(Alignment::Center)
+                    .bold(),
+            );
+            text.push_line(Line::default());
+            text.push_line(
+                Line::raw("Trust it and add it to known_hosts?")
+                    .alignment(Alignment::Center)
+                    .bold(),
+            );
+            let paragraph = Paragraph::new(text)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: false });
+            let popup = Popup::new(paragraph)
+                .title(String::from(" [Enter] Trust | [Esc] Reject "))
+                .title_position(Position::Bottom)
+                .borders(Borders::ALL)
+                .border_set(symbols::border::DOUBLE)
+                .border_style(Style::default().fg(get_color(|t| &t.toast.warn)));
+            frame.render_widget(popup, popup_area);
+        }
+        Ok(())
+    }
+
     fn render_toast(&mut self, frame: &mut Frame) -> Result<()> {
         if let Some(toast) = &self.toast && toast.expired_at > Instant::now() {
             let rect = frame.area();
@@ -263,11 +431,14 @@ impl Renderable for Context {
         let vertical = Layout::vertical([Fill(1), Max(1)]);
         let [inner_area, footer_area] = vertical.areas(rest_area);
 
-        let horizontal = Layout::horizontal([Min(0), Length(20), Length(5)]);
-        let [tabs_area, title_area, fps_area] = horizontal.areas(header_area);
+        let workspaces_line = self.workspaces_line();
+        let horizontal = Layout::horizontal([Min(0), Length(workspaces_line.width() as u16 + 1), Length(15), Length(20), Length(5)]);
+        let [tabs_area, workspaces_area, status_area, title_area, fps_area] = horizontal.areas(header_area);
 
         self.render_bg(frame, frame.area())?;
         self.render_tabs(frame, tabs_area)?;
+        frame.render_widget(workspaces_line, workspaces_area);
+        self.render_connection_status(frame, status_area)?;
         self.render_title(frame, title_area)?;
         self.render_fps(frame, fps_area)?;
         self.render_separator(frame, separator_area)?;
@@ -283,7 +454,9 @@ impl Renderable for Context {
 
         self.render_footer(frame, footer_area)?;
         self.render_server_switcher(frame, rect)?;
+        self.render_db_switcher(frame, rect)?;
         self.render_app_options(frame, rect)?;
+        self.render_host_key_prompt(frame, rect)?;
         self.render_toast(frame)?;
 
         Ok(())
@@ -291,20 +464,35 @@ impl Renderable for Context {
 
     fn footer_elements(&self) -> Vec<(&str, &str)> {
         let mut elements = vec![];
+        if self.host_key_prompt.is_some() {
+            elements.push(("Enter", "Trust"));
+            elements.push(("Esc", "Reject"));
+            return elements;
+        }
         if self.show_server_switcher {
             elements.extend(self.server_list.footer_elements());
         }
+        if self.show_db_switcher {
+            elements.extend(self.db_switcher.footer_elements());
+        }
         if self.show_app_options {
             elements.extend(self.app_options.footer_elements());
         }
 
-        if !self.show_server_switcher && !self.show_app_options {
+        if !self.show_server_switcher && !self.show_db_switcher && !self.show_app_options {
             elements.extend(match self.current_tab {
                 CurrentTab::Explorer => self.explorer_tab.footer_elements(),
                 CurrentTab::Cli => self.cli_tab.footer_elements(),
+                CurrentTab::Scripts => self.scripts_tab.footer_elements(),
+                CurrentTab::Dashboard => self.dashboard_tab.footer_elements(),
                 CurrentTab::Logger => self.logger_tab.footer_elements(),
             });
             elements.push(("s", "Server"));
+            elements.push(("b", "Database"));
+            if workspaces().len() > 1 {
+                elements.push(("w", "Workspace"));
+            }
+            elements.push(("^w", "Close Workspace"));
             elements.push(("o", "Options"));
         }
 
@@ -323,6 +511,15 @@ impl Renderable for Context {
             self.toast = None;
             needed = true;
         }
+        if self.host_key_prompt.is_none()
+            && let Some(prompt) = try_recv_host_key_prompt()
+        {
+            self.host_key_prompt = Some(prompt);
+            needed = true;
+        }
+        if self.show_db_switcher && self.db_switcher.handle_data()? {
+            needed = true;
+        }
         let current_tab = self.get_current_tab_as_mut();
         let current_tab_needed = current_tab.handle_data()?;
         Ok(needed || current_tab_needed)
@@ -331,6 +528,18 @@ impl Renderable for Context {
 
 impl Listenable for Context {
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if let Some(prompt) = self.host_key_prompt.take() {
+            match key_event.code {
+                KeyCode::Enter => prompt.accept(),
+                KeyCode::Esc => prompt.reject(),
+                _ => {
+                    self.host_key_prompt = Some(prompt);
+                    return Ok(!key_event.is_c_c());
+                }
+            }
+            return Ok(true);
+        }
+
         if self.show_server_switcher {
             if self.server_list.handle_key_event(key_event)? {
                 return Ok(true);
@@ -341,6 +550,19 @@ impl Listenable for Context {
             }
         }
 
+        if self.show_db_switcher {
+            if self.db_switcher.handle_key_event(key_event)? {
+                if key_event.code == KeyCode::Enter {
+                    self.show_db_switcher = false;
+                }
+                return Ok(true);
+            }
+            if none_match(&key_event, KeyCode::Esc) {
+                self.show_db_switcher = false;
+                return Ok(true);
+            }
+        }
+
         if self.show_app_options {
             if self.app_options.handle_key_event(key_event)? {
                 return Ok(true);
@@ -375,6 +597,19 @@ impl Listenable for Context {
             self.show_server_switcher = true;
             return Ok(true);
         }
+        if key_event.is_n_b() {
+            self.show_db_switcher = true;
+            self.db_switcher.refresh()?;
+            return Ok(true);
+        }
+        if key_event.is_n_w() {
+            self.next_workspace()?;
+            return Ok(true);
+        }
+        if key_event.is_c_w() {
+            close_workspace()?;
+            return Ok(true);
+        }
         if key_event.is_n_o() {
             self.show_app_options = true;
             self.app_options = Options::default();
@@ -386,12 +621,21 @@ impl Listenable for Context {
     }
 
     fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if self.host_key_prompt.is_some() {
+            return Ok(true);
+        }
         if self.show_server_switcher {
             if !self.server_list.handle_mouse_event(mouse_event)? && mouse_event.is_left_up() {
                 self.show_server_switcher = false;
             }
             return Ok(true);
         }
+        if self.show_db_switcher {
+            if !self.db_switcher.handle_mouse_event(mouse_event)? && mouse_event.is_left_up() {
+                self.show_db_switcher = false;
+            }
+            return Ok(true);
+        }
         if self.show_app_options {
             if !self.app_options.handle_mouse_event(mouse_event)? && mouse_event.is_left_up() {
                 self.show_app_options = false;
@@ -428,9 +672,12 @@ impl Listenable for Context {
     }
 
     fn on_app_event(&mut self, app_event: AppEvent) -> Result<()> {
+        // restored tabs already belong to the connection, a rescan would reset them
+        let mut restored_workspace = false;
         match app_event.clone() {
             AppEvent::InitConfig(app_config, arguments) => {
                 self.initial_configuration = Arc::clone(&app_config);
+                self.initial_arguments = Arc::clone(&arguments);
                 apply_theme(&arguments, &app_config)?;
                 let db_config = if arguments.once {
                     Databases::empty()
@@ -438,21 +685,30 @@ impl Listenable for Context {
                     load_database_configuration()?
                 };
                 self.server_list = ServerList::new(&db_config);
+                force_read_only(arguments.readonly);
                 apply_db(&arguments, &db_config)?;
             }
             AppEvent::Bus(global_event) => match global_event {
                 GlobalEvent::ClientChanged => {
+                    restored_workspace = self.sync_workspace()?;
                     let v = redis_operations()
                         .and_then(|opt| opt.get_server_info("redis_version"))
                         .unwrap_or("?.?.?".to_string());
                     self.title = format!("redis ver: {v}");
                 }
+                GlobalEvent::ConnectionStatus(name, status) => {
+                    self.connection_status.insert(name, status);
+                }
                 _ => {}
             },
             _ => {}
         }
-        self.explorer_tab.on_app_event(app_event.clone())?;
-        self.cli_tab.on_app_event(app_event.clone())?;
+        if !restored_workspace {
+            self.explorer_tab.on_app_event(app_event.clone())?;
+            self.cli_tab.on_app_event(app_event.clone())?;
+        }
+        self.scripts_tab.on_app_event(app_event.clone())?;
+        self.dashboard_tab.on_app_event(app_event.clone())?;
         self.logger_tab.on_app_event(app_event.clone())?;
         self.server_list.on_app_event(app_event.clone())?;
         self.app_options.on_app_event(app_event.clone())?;
@@ -471,6 +727,10 @@ fn apply_theme(app_arguments: &AppArguments, app_config: &Configuration) -> Resu
 }
 
 fn apply_db(app_arguments: &AppArguments, db_config: &Databases) -> Result<()> {
+    if let Some(database) = app_arguments.ad_hoc_database()? {
+        // ad-hoc connections are never written to databases.ron
+        return switch_client(database.location(), &database);
+    }
     let default_db = app_arguments
         .target
         .clone()
@@ -34,7 +34,11 @@ use ratisui_core::bus::{
     subscribe_message_channel,
 };
 use ratisui_core::cli::AppArguments;
-use ratisui_core::configuration::{Configuration, load_app_configuration};
+use ratisui_core::configuration::{
+    Configuration, load_app_configuration, load_database_configuration, load_database_encryption,
+    save_database_configuration,
+};
+use ratisui_core::credentials;
 use ratisui_core::input::InputEvent;
 use ratisui_core::marcos::KeyAsserter;
 use std::cmp;
@@ -51,6 +55,10 @@ async fn main() -> Result<()> {
     tui_logger::init_logger(log::LevelFilter::Trace).map_err(|e| anyhow!(e))?;
     tui_logger::set_default_level(log::LevelFilter::Trace);
 
+    if !arguments.once {
+        unlock_credentials(load_app_configuration()?.encrypt_credentials)?;
+    }
+
     let mut app_result;
     loop {
         let app_config = load_app_configuration()?;
@@ -79,6 +87,39 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Ask for the master passphrase before the terminal enters raw mode, when `databases.ron`
+/// is encrypted or should become encrypted.
+fn unlock_credentials(encrypt: bool) -> Result<()> {
+    let encryption = load_database_encryption()?;
+    let migrate = match (&encryption, encrypt) {
+        (None, false) => return Ok(()),
+        (Some(encryption), _) => {
+            let passphrase = rpassword::prompt_password("Master passphrase: ")?;
+            credentials::unlock(&passphrase, encryption)?;
+            !encrypt
+        }
+        (None, true) => {
+            let passphrase = rpassword::prompt_password("New master passphrase: ")?;
+            let confirmation = rpassword::prompt_password("Confirm master passphrase: ")?;
+            if passphrase.is_empty() || passphrase != confirmation {
+                bail!("Master passphrases are empty or do not match");
+            }
+            credentials::init(&passphrase)?;
+            true
+        }
+    };
+    if migrate {
+        let databases = load_database_configuration()?;
+        if !encrypt {
+            // encryption was turned off, write the credentials back as base64
+            credentials::lock()?;
+        }
+        save_database_configuration(&databases)?;
+        info!("Credentials in databases.ron are now {}", if encrypt { "encrypted" } else { "decrypted" });
+    }
+    Ok(())
+}
+
 async fn run(
     mut app: App,
     mut terminal: TerminalBackEnd,
@@ -1,6 +1,7 @@
-use crate::app::{AppEvent, Listenable, Renderable, TabImplementation};
+use crate::app::{centered_rect, AppEvent, Listenable, Renderable, TabImplementation};
 use crate::components::console_output::{ConsoleData, OutputKind};
-use crate::components::redis_cli::RedisCli;
+use crate::components::popup::Popup;
+use crate::components::redis_cli::{command_keys, is_dangerous_command, is_rejected_in_read_only, RedisCli};
 use anyhow::{Error, Result};
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use deadpool_redis::redis::{Cmd, Value, VerbatimFormat};
@@ -9,19 +10,20 @@ use ratatui::crossterm::event::{
     KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
 };
 use ratatui::layout::Constraint::{Fill, Length, Min};
-use ratatui::layout::{Layout, Rect};
-use ratatui::prelude::{Line, Stylize};
+use ratatui::layout::{Alignment, Layout, Rect};
+use ratatui::prelude::{Line, Stylize, Text};
 use ratatui::style::{Color, Style};
 use ratatui::text::Span;
-use ratatui::widgets::WidgetRef;
-use ratatui::Frame;
+use ratatui::widgets::block::Position;
+use ratatui::widgets::{Borders, Paragraph, WidgetRef};
+use ratatui::{symbols, Frame};
 use ratisui_core::bus::{publish_event, GlobalEvent};
 use ratisui_core::configuration::{load_history, save_history, CliOutputFormatKind};
 use ratisui_core::marcos::KeyAsserter;
-use ratisui_core::redis_opt::{spawn_redis_opt, Disposable, DisposableMonitor};
+use ratisui_core::redis_opt::{is_read_only, redis_operations, spawn_redis_opt, Disposable, DisposableMonitor};
 use ratisui_core::serde_wrapper::to_ron_string;
 use ratisui_core::theme::get_color;
-use ratisui_core::utils::{deserialize_bytes, escape_string, split_args, try_decode_arg, ContentType};
+use ratisui_core::utils::{deserialize_bytes, escape_string, key_slot, split_args, try_decode_arg, ContentType};
 use std::cmp;
 use std::collections::VecDeque;
 use std::sync::{Arc, RwLock};
@@ -47,6 +49,28 @@ pub struct CliTab {
     input_throbber_state: ThrobberState,
 
     output_format: CliOutputFormatKind,
+    dangerous_commands: Vec<String>,
+    /// Dangerous command waiting for confirmation, with the target it will run on.
+    pending_command: Option<(Vec<String>, String)>,
+    /// Commands queued after `MULTI` or inside `pipeline { ... }`.
+    batch: Option<Batch>,
+}
+
+/// Commands sent together on one connection once the block is closed.
+struct Batch {
+    /// `MULTI` ... `EXEC` when true, `pipeline {` ... `}` otherwise.
+    atomic: bool,
+    commands: Vec<Vec<String>>,
+}
+
+impl Batch {
+    const fn new(atomic: bool) -> Self {
+        Self { atomic, commands: vec![] }
+    }
+
+    const fn name(&self) -> &'static str {
+        if self.atomic { "MULTI" } else { "PIPELINE" }
+    }
 }
 
 #[derive(Default, PartialEq, Eq, Clone, Display)]
@@ -85,14 +109,22 @@ impl Renderable for CliTab {
         let throbber = Throbber::default().throbber_set(throbber_widgets_tui::BRAILLE_EIGHT_DOUBLE);
         let horizontal = Layout::horizontal([Length(2), Fill(0)]).split(vertical[2]);
         frame.render_stateful_widget(throbber, horizontal[0], &mut self.input_throbber_state);
-        frame.render_widget(Span::raw(format!("- {} -", self.mode)), horizontal[1]);
+        let status = match &self.batch {
+            Some(batch) => format!("- {} - {} ({} queued)", self.mode, batch.name(), batch.commands.len()),
+            None => format!("- {} -", self.mode),
+        };
+        frame.render_widget(Span::raw(status), horizontal[1]);
         self.render_input(frame, session_vertical[1])?;
+        self.render_confirm_popup(frame, rect);
         Ok(())
     }
 
     fn footer_elements(&self) -> Vec<(&str, &str)> {
         let mut elements = vec![];
-        if self.is_listening() {
+        if self.pending_command.is_some() {
+            elements.push(("Enter", "Run"));
+            elements.push(("Esc", "Cancel"));
+        } else if self.is_listening() {
             elements.push(("Esc", "Stop"));
         } else {
             match self.mode {
@@ -159,6 +191,22 @@ impl Renderable for CliTab {
 impl Listenable for CliTab {
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
         if key_event.kind == KeyEventKind::Press {
+            if self.pending_command.is_some() {
+                match key_event.code {
+                    KeyCode::Enter => {
+                        if let Some((args, _)) = self.pending_command.take() {
+                            self.dispatch(args);
+                        }
+                    }
+                    KeyCode::Esc => {
+                        self.pending_command = None;
+                        self.console_data.push_err("Cancelled");
+                        self.unlock_input();
+                    }
+                    _ => {}
+                }
+                return Ok(true);
+            }
             if self.is_listening() {
                 match key_event {
                     KeyEvent {
@@ -366,6 +414,7 @@ impl Listenable for CliTab {
                 self.output_format = app_config.cli_output_format.clone();
                 self.console_capacity = app_config.console_capacity;
                 self.console_data = ConsoleData::new(self.console_capacity);
+                self.dangerous_commands = app_config.dangerous_commands.clone();
             }
             AppEvent::Destroy => {
                 if self.history_max_size != 0 {
@@ -405,6 +454,9 @@ impl CliTab {
             listen_state: (ThrobberState::default(), Instant::now()),
             input_throbber_state: ThrobberState::default(),
             output_format: CliOutputFormatKind::default(),
+            dangerous_commands: vec![],
+            pending_command: None,
+            batch: None,
         }
     }
 
@@ -464,6 +516,9 @@ impl CliTab {
   clear          Clean buffer
   exit           Exit
   help           Print help
+  MULTI          Queue commands until EXEC, or drop them with DISCARD
+                 in cluster mode all keys must hash to the same slot
+  pipeline {     Queue commands until }, then send them as one pipeline
 
 Binary:
   base64:        base64#YmFzZTY0#
@@ -479,6 +534,152 @@ Example:
         }
 
         let args = split_args(&command);
+        if self.handle_batch(&command, &args) {
+            return;
+        }
+        if is_rejected_in_read_only(&args) && is_read_only() {
+            self.console_data.push_err(format!("(read-only) {} can modify the server, rejected", args[0].to_uppercase()));
+            self.unlock_input();
+            return;
+        }
+        if is_dangerous_command(&args, &self.dangerous_commands) {
+            let target = redis_operations()
+                .map(|operations| format!("{} (db {})", operations.name, operations.database().db))
+                .unwrap_or_else(|| "unknown server".to_string());
+            self.pending_command = Some((args, target));
+            return;
+        }
+        self.dispatch(args);
+    }
+
+    /// Start, send or discard a `MULTI` or pipeline block, returns false for commands to run or queue.
+    fn handle_batch(&mut self, command: &str, args: &[String]) -> bool {
+        let name = args.first().map(|arg| arg.to_uppercase()).unwrap_or_default();
+        let is_pipeline_start = command
+            .split_whitespace()
+            .collect::<String>()
+            .eq_ignore_ascii_case("pipeline{");
+        match (self.batch.as_ref().map(|batch| batch.atomic), name.as_str()) {
+            (None, "MULTI") if args.len() == 1 => {
+                self.batch = Some(Batch::new(true));
+                self.console_data.push_std("OK");
+            }
+            (None, _) if is_pipeline_start => {
+                self.batch = Some(Batch::new(false));
+                self.console_data.push_std("Pipeline started, send it with }");
+            }
+            (Some(true), "EXEC") | (Some(false), "}") => {
+                self.send_batch();
+                return true;
+            }
+            (Some(true), "DISCARD") => {
+                self.batch = None;
+                self.console_data.push_std("OK");
+            }
+            (Some(_), "MULTI") => self.console_data.push_err("ERR MULTI calls can not be nested"),
+            (Some(_), _) if is_pipeline_start => {
+                self.console_data.push_err("ERR pipeline blocks can not be nested");
+            }
+            (None, "EXEC" | "DISCARD") => self.console_data.push_err(format!("ERR {name} without MULTI")),
+            _ => return false,
+        }
+        self.unlock_input();
+        true
+    }
+
+    /// Queue the command when a block is open, run it otherwise.
+    fn dispatch(&mut self, args: Vec<String>) {
+        let Some(batch) = self.batch.as_mut() else {
+            self.execute(args);
+            return;
+        };
+        let name = args.first().map(|arg| arg.to_lowercase()).unwrap_or_default();
+        if matches!(name.as_str(), "monitor" | "subscribe" | "psubscribe") {
+            self.console_data.push_err(format!("ERR {name} is not allowed in {}", batch.name()));
+        } else {
+            batch.commands.push(args);
+            self.console_data.push_std("QUEUED");
+        }
+        self.unlock_input();
+    }
+
+    fn send_batch(&mut self) {
+        let Some(batch) = self.batch.take() else {
+            return;
+        };
+        if batch.commands.is_empty() {
+            self.console_data.push_std("(empty array)");
+            self.unlock_input();
+            return;
+        }
+        if batch.atomic && redis_operations().is_some_and(|operations| operations.is_cluster()) {
+            // a transaction runs on a single node, checked here instead of failing halfway with CROSSSLOT
+            let slots = batch
+                .commands
+                .iter()
+                .flat_map(|args| command_keys(args))
+                .filter_map(|key| try_decode_arg(key).ok())
+                .map(|key| key_slot(&key))
+                .unique()
+                .count();
+            if slots > 1 {
+                self.console_data.push_err(
+                    "CROSSSLOT Keys in MULTI hash to different slots, use a {hash tag} or pipeline { } in cluster mode",
+                );
+                self.unlock_input();
+                return;
+            }
+        }
+        let sender = self.data_sender.clone();
+        let result = spawn_redis_opt(move |operations| async move {
+            let mut cmds = vec![];
+            for args in batch.commands.iter() {
+                match to_cmd(args) {
+                    Ok(cmd) => cmds.push(cmd),
+                    Err(e) => {
+                        sender.send(error_value(e))?;
+                        return Ok(());
+                    }
+                }
+            }
+            match operations.pipeline(cmds, batch.atomic).await {
+                Ok(value) => sender.send(value)?,
+                Err(e) => sender.send(error_value(e))?,
+            }
+            Ok::<(), Error>(())
+        });
+        if let Err(e) = result {
+            self.console_data.push_err(format!("{}", e));
+            self.unlock_input();
+        }
+    }
+
+    fn unlock_input(&mut self) {
+        self.lock_input = false;
+        self.console_data.push_std("");
+        self.console_data.build_paragraph();
+    }
+
+    fn render_confirm_popup(&self, frame: &mut Frame, rect: Rect) {
+        if let Some((args, target)) = &self.pending_command {
+            let popup_area = centered_rect(40, 20, rect);
+            let mut text = Text::default();
+            text.push_line(Line::raw(args.join(" ")).alignment(Alignment::Center).bold());
+            text.push_line(Line::default());
+            text.push_line(Line::raw(format!("Will be executed on {target}")).alignment(Alignment::Center));
+            text.push_line(Line::raw("Are you sure?").alignment(Alignment::Center).bold());
+            let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+            let popup = Popup::new(paragraph)
+                .title(String::from(" [Enter] Run | [Esc] Cancel "))
+                .title_position(Position::Bottom)
+                .borders(Borders::ALL)
+                .border_set(symbols::border::DOUBLE)
+                .style(Style::default().bg(get_color(|t| &t.tab.cli.accent)));
+            frame.render_widget(popup, popup_area);
+        }
+    }
+
+    fn execute(&mut self, args: Vec<String>) {
         let result = if args.len() == 1 && "monitor".eq_ignore_ascii_case(&args[0]) {
             self.do_dispose();
             let arc = Arc::clone(&self.disposable);
@@ -515,29 +716,16 @@ Example:
         } else {
             let sender = self.data_sender.clone();
             spawn_redis_opt(move |operations| async move {
-                let mut cmd = Cmd::new();
-                for arg in args.iter() {
-                    match try_decode_arg(arg) {
-                        Ok(vec) => {
-                            cmd.arg(vec);
-                        }
-                        Err(e) => {
-                            sender.send(Value::VerbatimString {
-                                format: VerbatimFormat::Unknown(String::from("ERROR")),
-                                text: format!("{:?}", e),
-                            })?;
-                            return Ok(());
-                        }
+                let cmd = match to_cmd(&args) {
+                    Ok(cmd) => cmd,
+                    Err(e) => {
+                        sender.send(error_value(e))?;
+                        return Ok(());
                     }
-                }
+                };
                 match operations.cmd(cmd).await {
                     Ok(value) => sender.send(value)?,
-                    Err(e) => {
-                        sender.send(Value::VerbatimString {
-                            format: VerbatimFormat::Unknown(String::from("ERROR")),
-                            text: format!("{:?}", e),
-                        })?;
-                    }
+                    Err(e) => sender.send(error_value(e))?,
                 }
                 Ok(())
             })
@@ -546,9 +734,7 @@ Example:
         if let Err(e) = result {
             let string = format!("{}", e);
             self.console_data.push_err(string);
-            self.lock_input = false;
-            self.console_data.push_std("");
-            self.console_data.build_paragraph();
+            self.unlock_input();
         }
     }
 
@@ -619,10 +805,31 @@ Example:
     }
 
     fn value_to_lines(&self, value: &Value, pad: u16) -> Vec<(OutputKind, String)> {
-        match self.output_format {
-            CliOutputFormatKind::Redis => value_to_lines_in_redis(value, pad),
-            CliOutputFormatKind::Ron => value_to_lines_in_ron(value, pad),
-        }
+        value_to_lines(&self.output_format, value, pad)
+    }
+}
+
+/// Format a reply with the configured cli output format.
+pub fn value_to_lines(output_format: &CliOutputFormatKind, value: &Value, pad: u16) -> Vec<(OutputKind, String)> {
+    match output_format {
+        CliOutputFormatKind::Redis => value_to_lines_in_redis(value, pad),
+        CliOutputFormatKind::Ron => value_to_lines_in_ron(value, pad),
+    }
+}
+
+/// Build the command, arguments like `base64#...#` are decoded first.
+fn to_cmd(args: &[String]) -> Result<Cmd> {
+    let mut cmd = Cmd::new();
+    for arg in args.iter() {
+        cmd.arg(try_decode_arg(arg)?);
+    }
+    Ok(cmd)
+}
+
+pub fn error_value(e: Error) -> Value {
+    Value::VerbatimString {
+        format: VerbatimFormat::Unknown(String::from("ERROR")),
+        text: format!("{:?}", e),
     }
 }
 
@@ -0,0 +1,631 @@
+use crate::app::{AppEvent, Listenable, Renderable, ServerPanel, TabImplementation};
+use crate::components::clients_panel::ClientsPanel;
+use crate::components::config_panel::ConfigPanel;
+use crate::components::keyspace_panel::KeyspacePanel;
+use crate::components::latency_panel::LatencyPanel;
+use crate::components::slowlog_panel::SlowlogPanel;
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use deadpool_redis::redis::cmd;
+use log::warn;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length, Percentage};
+use ratatui::layout::{Layout, Rect};
+use ratatui::prelude::{Line, Stylize, Text};
+use ratatui::style::{Color, Style};
+use ratatui::symbols::Marker;
+use ratatui::text::Span;
+use ratatui::widgets::{
+    Axis, Block, Cell, Chart, Dataset, GraphType, HighlightSpacing, Paragraph, Row, Sparkline, Table, TableState,
+};
+use ratatui::Frame;
+use ratisui_core::bus::GlobalEvent;
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{spawn_redis_opt, Node};
+use ratisui_core::server::{Info, Metrics};
+use ratisui_core::theme::get_color;
+use ratisui_core::utils::human_bytes;
+use std::collections::VecDeque;
+use std::ops::Add;
+use std::time::{Duration, Instant};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const HISTORY_CAPACITY: usize = 300;
+
+/// Live `INFO` metrics of the active connection, polled while the tab is shown.
+pub struct DashboardTab {
+    panel: Panel,
+    slowlog_panel: SlowlogPanel,
+    clients_panel: ClientsPanel,
+    latency_panel: LatencyPanel,
+    keyspace_panel: KeyspacePanel,
+    config_panel: ConfigPanel,
+    last_poll: Option<Instant>,
+    polling: bool,
+    history: VecDeque<Sample>,
+    nodes: Vec<NodeStats>,
+    show_info: bool,
+    info_scroll: u16,
+    node_state: TableState,
+    data_sender: Sender<Option<Vec<(Node, Info)>>>,
+    data_receiver: Receiver<Option<Vec<(Node, Info)>>>,
+
+    body_area: Rect,
+}
+
+/// Views of the tab, switched with their number key.
+#[derive(Clone, Copy, Eq, PartialEq, Display, EnumIter)]
+enum Panel {
+    Overview,
+    Slowlog,
+    Clients,
+    Latency,
+    Keyspace,
+    Config,
+}
+
+/// Metrics of the masters at one poll.
+#[derive(Clone, Copy, Default)]
+struct Sample {
+    metrics: Metrics,
+    /// Hit ratio of the lookups since the previous sample.
+    hit_ratio: Option<f64>,
+    evictions: u64,
+}
+
+struct NodeStats {
+    node: Node,
+    info: Info,
+    metrics: Metrics,
+}
+
+impl TabImplementation for DashboardTab {
+    fn highlight(&self) -> Color {
+        get_color(|t| &t.tab.dashboard.highlight)
+    }
+
+    fn title(&self) -> Line<'static> {
+        " Dashboard  "
+            .fg(get_color(|t| &t.tab.title))
+            .bg(get_color(|t| &t.tab.dashboard.accent))
+            .into()
+    }
+}
+
+impl DashboardTab {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        Self {
+            panel: Panel::Overview,
+            slowlog_panel: SlowlogPanel::new(),
+            clients_panel: ClientsPanel::new(),
+            latency_panel: LatencyPanel::new(),
+            keyspace_panel: KeyspacePanel::new(),
+            config_panel: ConfigPanel::new(),
+            last_poll: None,
+            polling: false,
+            history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            nodes: vec![],
+            show_info: false,
+            info_scroll: 0,
+            node_state: TableState::default(),
+            data_sender: tx,
+            data_receiver: rx,
+            body_area: Rect::default(),
+        }
+    }
+
+    fn poll(&mut self) {
+        self.last_poll = Some(Instant::now());
+        let sender = self.data_sender.clone();
+        let result = spawn_redis_opt(move |operations| async move {
+            match operations.node_cmd::<String>(&cmd("INFO"), false).await {
+                Ok(replies) => {
+                    let infos = replies
+                        .into_iter()
+                        .map(|(node, info)| (node, Info::parse(&info)))
+                        .collect();
+                    sender.send(Some(infos))?;
+                }
+                Err(e) => {
+                    warn!("Failed to poll INFO: {}", e);
+                    sender.send(None)?;
+                }
+            }
+            Ok::<(), Error>(())
+        });
+        self.polling = result.is_ok();
+    }
+
+    fn update(&mut self, replies: Vec<(Node, Info)>) {
+        self.nodes = replies
+            .into_iter()
+            .map(|(node, info)| {
+                let metrics = Metrics::from_info(&info);
+                NodeStats { node, info, metrics }
+            })
+            .collect();
+        let metrics = self
+            .nodes
+            .iter()
+            .filter(|stats| stats.node.is_master)
+            .map(|stats| stats.metrics)
+            .fold(Metrics::default(), Add::add);
+        let previous = self.history.back().map(|sample| sample.metrics);
+        if self.history.len() == HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+        self.history.push_back(Sample {
+            metrics,
+            hit_ratio: metrics.hit_ratio(previous.as_ref()),
+            evictions: metrics.evictions_since(previous.as_ref()),
+        });
+        let selected = match self.node_state.selected() {
+            _ if self.nodes.is_empty() => None,
+            Some(i) => Some(i.min(self.nodes.len() - 1)),
+            None => Some(0),
+        };
+        self.node_state.select(selected);
+    }
+
+    fn server_panel(&self) -> Option<&dyn ServerPanel> {
+        match self.panel {
+            Panel::Overview => None,
+            Panel::Slowlog => Some(&self.slowlog_panel),
+            Panel::Clients => Some(&self.clients_panel),
+            Panel::Latency => Some(&self.latency_panel),
+            Panel::Keyspace => Some(&self.keyspace_panel),
+            Panel::Config => Some(&self.config_panel),
+        }
+    }
+
+    fn server_panel_mut(&mut self) -> Option<&mut dyn ServerPanel> {
+        match self.panel {
+            Panel::Overview => None,
+            Panel::Slowlog => Some(&mut self.slowlog_panel),
+            Panel::Clients => Some(&mut self.clients_panel),
+            Panel::Latency => Some(&mut self.latency_panel),
+            Panel::Keyspace => Some(&mut self.keyspace_panel),
+            Panel::Config => Some(&mut self.config_panel),
+        }
+    }
+
+    fn switch_panel(&mut self, panel: Panel) -> Result<()> {
+        if self.panel == panel {
+            return Ok(());
+        }
+        self.panel = panel;
+        if let Some(server_panel) = self.server_panel_mut() {
+            server_panel.refresh()?;
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.slowlog_panel.clear();
+        self.clients_panel.clear();
+        self.latency_panel.clear();
+        self.keyspace_panel.clear();
+        self.config_panel.clear();
+        self.history.clear();
+        self.nodes.clear();
+        self.node_state.select(None);
+        self.last_poll = None;
+        self.info_scroll = 0;
+    }
+
+    fn is_cluster(&self) -> bool {
+        self.nodes.len() > 1 || self.nodes.iter().any(|stats| !stats.node.id.is_empty())
+    }
+
+    fn selected_node(&self) -> Option<&NodeStats> {
+        self.node_state
+            .selected()
+            .and_then(|i| self.nodes.get(i))
+            .or_else(|| self.nodes.first())
+    }
+
+    fn next_node(&mut self) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let i = match self.node_state.selected() {
+            Some(i) if i + 1 < self.nodes.len() => i + 1,
+            _ => 0,
+        };
+        self.node_state.select(Some(i));
+        self.info_scroll = 0;
+    }
+
+    fn previous_node(&mut self) {
+        if self.nodes.is_empty() {
+            return;
+        }
+        let i = match self.node_state.selected() {
+            Some(0) | None => self.nodes.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.node_state.select(Some(i));
+        self.info_scroll = 0;
+    }
+
+    fn render_summary(&self, frame: &mut Frame, rect: Rect) {
+        let panel_bar = self.panel_bar();
+        let [rect, panel_bar_area] = Layout::horizontal([Fill(1), Length(panel_bar.width() as u16)]).areas(rect);
+        frame.render_widget(panel_bar, panel_bar_area);
+        let Some(stats) = self.nodes.first() else {
+            frame.render_widget(Span::raw("Waiting for INFO...").dim(), rect);
+            return;
+        };
+        let info = &stats.info;
+        let uptime = chronoutil::RelativeDuration::seconds(info.get_u64("uptime_in_seconds") as i64)
+            .format_to_iso8601();
+        let mut spans = vec![
+            Span::raw(format!("redis {}", info.get("redis_version").unwrap_or("?.?.?"))).bold(),
+            Span::raw(format!(" | {}", info.get("redis_mode").unwrap_or("standalone"))),
+        ];
+        if self.is_cluster() {
+            let masters = self.nodes.iter().filter(|stats| stats.node.is_master).count();
+            spans.push(Span::raw(format!(" | {} nodes, {masters} masters", self.nodes.len())));
+        } else {
+            spans.push(Span::raw(format!(" | role {}", info.get("role").unwrap_or("?"))));
+            spans.push(Span::raw(format!(" | uptime {uptime}")));
+        }
+        spans.push(Span::raw(format!(" | every {}s", POLL_INTERVAL.as_secs())).dim());
+        frame.render_widget(Line::from(spans), rect);
+    }
+
+    fn panel_bar(&self) -> Line<'static> {
+        let spans = Panel::iter()
+            .enumerate()
+            .flat_map(|(i, panel)| {
+                let span = Span::raw(format!(" {}:{panel} ", i + 1));
+                let span = if panel == self.panel {
+                    span.fg(get_color(|t| &t.tab.title)).bg(get_color(|t| &t.tab.dashboard.accent))
+                } else {
+                    span
+                };
+                [span, Span::raw(" ")]
+            })
+            .collect::<Vec<_>>();
+        Line::from(spans)
+    }
+
+    fn render_charts(&self, frame: &mut Frame, rect: Rect) {
+        let [top, bottom] = Layout::vertical([Percentage(50), Percentage(50)]).areas(rect);
+        let [ops_area, memory_area, clients_area] =
+            Layout::horizontal([Fill(1), Fill(1), Fill(1)]).areas(top);
+        let [hit_ratio_area, evictions_area, network_area] =
+            Layout::horizontal([Fill(1), Fill(1), Fill(1)]).areas(bottom);
+        let latest = self.history.back().copied().unwrap_or_default();
+
+        let ops = self.history.iter().map(|sample| sample.metrics.ops_per_sec).collect::<Vec<_>>();
+        render_sparkline(
+            frame,
+            ops_area,
+            format!("Ops/sec {}", latest.metrics.ops_per_sec),
+            &ops,
+            get_color(|t| &t.raw.number),
+        );
+
+        let memory = self.series(|sample| Some(sample.metrics.used_memory as f64));
+        let memory_max = memory.iter().map(|(_, y)| *y).fold(0.0, f64::max);
+        render_chart(
+            frame,
+            memory_area,
+            format!("Memory {}", human_bytes(latest.metrics.used_memory)),
+            vec![("used", memory, get_color(|t| &t.raw.property))],
+            memory_max * 1.2,
+            |y| human_bytes(y as u64),
+        );
+
+        let clients = self.history.iter().map(|sample| sample.metrics.connected_clients).collect::<Vec<_>>();
+        render_sparkline(
+            frame,
+            clients_area,
+            format!("Clients {}", latest.metrics.connected_clients),
+            &clients,
+            get_color(|t| &t.raw.string),
+        );
+
+        let hit_ratio = self.series(|sample| sample.hit_ratio);
+        let hit_ratio_title = latest
+            .hit_ratio
+            .map_or_else(|| "Hit ratio -".to_string(), |ratio| format!("Hit ratio {ratio:.1}%"));
+        render_chart(
+            frame,
+            hit_ratio_area,
+            hit_ratio_title,
+            vec![("hit", hit_ratio, get_color(|t| &t.toast.info))],
+            100.0,
+            |y| format!("{y:.0}%"),
+        );
+
+        let evictions = self.history.iter().map(|sample| sample.evictions).collect::<Vec<_>>();
+        render_sparkline(
+            frame,
+            evictions_area,
+            format!("Evictions {} (total {})", latest.evictions, latest.metrics.evicted_keys),
+            &evictions,
+            get_color(|t| &t.toast.error),
+        );
+
+        let input = self.series(|sample| Some(sample.metrics.input_kbps));
+        let output = self.series(|sample| Some(sample.metrics.output_kbps));
+        let network_max = input.iter().chain(output.iter()).map(|(_, y)| *y).fold(0.0, f64::max);
+        render_chart(
+            frame,
+            network_area,
+            format!(
+                "Network in {:.2} / out {:.2} KB/s",
+                latest.metrics.input_kbps, latest.metrics.output_kbps
+            ),
+            vec![
+                ("in", input, get_color(|t| &t.raw.number)),
+                ("out", output, get_color(|t| &t.raw.string)),
+            ],
+            network_max * 1.2,
+            |y| format!("{y:.1}"),
+        );
+    }
+
+    /// Points of the history, x is the sample index.
+    fn series(&self, f: impl Fn(&Sample) -> Option<f64>) -> Vec<(f64, f64)> {
+        self.history
+            .iter()
+            .enumerate()
+            .filter_map(|(x, sample)| f(sample).map(|y| (x as f64, y)))
+            .collect()
+    }
+
+    fn render_nodes(&mut self, frame: &mut Frame, rect: Rect) {
+        let header = ["Node", "Role", "Ops/sec", "Memory", "Clients", "Hit ratio", "Evicted", "In/Out KB/s"]
+            .into_iter()
+            .map(|title| Cell::from(Text::raw(title)))
+            .collect::<Row>()
+            .style(
+                Style::default()
+                    .bold()
+                    .fg(get_color(|t| &t.table.header))
+                    .bg(get_color(|t| &t.table.header_bg)),
+            );
+        let rows = self
+            .nodes
+            .iter()
+            .map(|stats| {
+                let metrics = &stats.metrics;
+                [
+                    stats.node.addr.clone(),
+                    if stats.node.is_master { "master".to_string() } else { "replica".to_string() },
+                    metrics.ops_per_sec.to_string(),
+                    human_bytes(metrics.used_memory),
+                    metrics.connected_clients.to_string(),
+                    metrics.hit_ratio(None).map_or_else(|| "-".to_string(), |ratio| format!("{ratio:.1}%")),
+                    metrics.evicted_keys.to_string(),
+                    format!("{:.2}/{:.2}", metrics.input_kbps, metrics.output_kbps),
+                ]
+                .into_iter()
+                .map(|content| Cell::from(Text::raw(content)))
+                .collect::<Row>()
+                .style(Style::default().fg(get_color(|t| &t.table.row)))
+            })
+            .collect::<Vec<_>>();
+        let table = Table::new(
+            rows,
+            [Fill(2), Length(7), Fill(1), Fill(1), Fill(1), Fill(1), Fill(1), Fill(1)],
+        )
+        .header(header)
+        .block(Block::bordered().title("Nodes").border_style(get_color(|t| &t.border.default)))
+        .row_highlight_style(Style::default().bg(get_color(|t| &t.server.highlight)))
+        .highlight_symbol(Text::raw("➤ "))
+        .highlight_spacing(HighlightSpacing::Always);
+        frame.render_stateful_widget(table, rect, &mut self.node_state);
+    }
+
+    /// Every section of `INFO` of the selected node.
+    fn render_info(&self, frame: &mut Frame, rect: Rect) {
+        let mut text = Text::default();
+        if let Some(stats) = self.selected_node() {
+            for section in &stats.info.sections {
+                text.push_line(Line::raw(format!("# {}", section.name)).bold());
+                for (key, value) in &section.fields {
+                    text.push_line(Line::from(vec![
+                        Span::raw(key.clone()).fg(get_color(|t| &t.raw.property)),
+                        Span::raw(":"),
+                        Span::raw(value.clone()),
+                    ]));
+                }
+                text.push_line(Line::default());
+            }
+        }
+        let title = self
+            .selected_node()
+            .map_or_else(|| "INFO".to_string(), |stats| format!("INFO {}", stats.node.addr));
+        let paragraph = Paragraph::new(text)
+            .block(Block::bordered().title(title).border_style(get_color(|t| &t.border.highlight)))
+            .scroll((self.info_scroll, 0));
+        frame.render_widget(paragraph, rect);
+    }
+}
+
+impl Renderable for DashboardTab {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let nodes_height = if self.panel == Panel::Overview && self.is_cluster() {
+            Length((self.nodes.len() as u16).saturating_add(3).min(rect.height / 3))
+        } else {
+            Length(0)
+        };
+        let [summary_area, body_area, nodes_area] = Layout::vertical([Length(1), Fill(1), nodes_height]).areas(rect);
+        self.body_area = body_area;
+        self.render_summary(frame, summary_area);
+        if let Some(server_panel) = self.server_panel_mut() {
+            return server_panel.render_frame(frame, body_area);
+        }
+        if self.show_info {
+            self.render_info(frame, body_area);
+        } else {
+            self.render_charts(frame, body_area);
+        }
+        if self.is_cluster() {
+            self.render_nodes(frame, nodes_area);
+        }
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        if let Some(server_panel) = self.server_panel() {
+            let mut elements = server_panel.footer_elements();
+            elements.push(("1-6", "Panel"));
+            return elements;
+        }
+        let mut elements = vec![];
+        if self.show_info {
+            elements.push(("i", "Charts"));
+            elements.push(("↓/j", "Scroll Down"));
+            elements.push(("↑/k", "Scroll Up"));
+        } else {
+            elements.push(("i", "INFO"));
+        }
+        if self.is_cluster() {
+            elements.push(("]", "Next Node"));
+            elements.push(("[", "Prev Node"));
+        }
+        elements.push(("F5", "Refresh"));
+        elements.push(("1-6", "Panel"));
+        elements
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        if let Some(server_panel) = self.server_panel_mut() {
+            needed |= server_panel.handle_data()?;
+        }
+        while let Ok(replies) = self.data_receiver.try_recv() {
+            self.polling = false;
+            if let Some(replies) = replies {
+                self.update(replies);
+            }
+            needed = true;
+        }
+        if !self.polling && self.last_poll.is_none_or(|last_poll| last_poll.elapsed() >= POLL_INTERVAL) {
+            self.poll();
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for DashboardTab {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if let Some(server_panel) = self.server_panel_mut()
+            && server_panel.handle_key_event(key_event)?
+        {
+            return Ok(true);
+        }
+        if !key_event.modifiers.is_empty() {
+            return Ok(false);
+        }
+        match key_event.code {
+            KeyCode::Char(c @ '1'..='9') => match Panel::iter().nth(c as usize - '1' as usize) {
+                Some(panel) => self.switch_panel(panel)?,
+                None => return Ok(false),
+            },
+            _ if self.panel != Panel::Overview => return Ok(false),
+            KeyCode::Char('i') => {
+                self.show_info = !self.show_info;
+                self.info_scroll = 0;
+            }
+            KeyCode::Char('j') | KeyCode::Down if self.show_info => {
+                self.info_scroll = self.info_scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') | KeyCode::Up if self.show_info => {
+                self.info_scroll = self.info_scroll.saturating_sub(1);
+            }
+            KeyCode::Char(']') => self.next_node(),
+            KeyCode::Char('[') => self.previous_node(),
+            KeyCode::F(5) => {
+                if !self.polling {
+                    self.poll();
+                }
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        if let Some(server_panel) = self.server_panel_mut() {
+            return server_panel.handle_mouse_event(mouse_event);
+        }
+        if self.show_info && self.body_area.contains(mouse_event.as_position()) {
+            if mouse_event.is_scroll_down() {
+                self.info_scroll = self.info_scroll.saturating_add(3);
+            } else if mouse_event.is_scroll_up() {
+                self.info_scroll = self.info_scroll.saturating_sub(3);
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn on_app_event(&mut self, app_event: AppEvent) -> Result<()> {
+        if let AppEvent::Bus(GlobalEvent::ClientChanged) = app_event {
+            self.reset();
+        }
+        Ok(())
+    }
+}
+
+fn render_sparkline(frame: &mut Frame, rect: Rect, title: String, data: &[u64], color: Color) {
+    let block = Block::bordered().title(title).border_style(get_color(|t| &t.border.default));
+    let width = block.inner(rect).width as usize;
+    let data = data.iter().skip(data.len().saturating_sub(width)).copied().collect::<Vec<_>>();
+    let sparkline = Sparkline::default()
+        .block(block)
+        .data(&data)
+        .style(Style::default().fg(color));
+    frame.render_widget(sparkline, rect);
+}
+
+fn render_chart(
+    frame: &mut Frame,
+    rect: Rect,
+    title: String,
+    series: Vec<(&'static str, Vec<(f64, f64)>, Color)>,
+    y_max: f64,
+    y_label: fn(f64) -> String,
+) {
+    let x_max = series
+        .iter()
+        .flat_map(|(_, data, _)| data.iter().map(|(x, _)| *x))
+        .fold(1.0, f64::max);
+    let y_max = if y_max > 0.0 { y_max } else { 1.0 };
+    let datasets = series
+        .iter()
+        .map(|(name, data, color)| {
+            Dataset::default()
+                .name(*name)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(*color))
+                .data(data)
+        })
+        .collect::<Vec<_>>();
+    let chart = Chart::new(datasets)
+        .block(Block::bordered().title(title).border_style(get_color(|t| &t.border.default)))
+        .x_axis(Axis::default().bounds([0.0, x_max]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, y_max])
+                .labels(vec![Span::raw(y_label(0.0)), Span::raw(y_label(y_max))]),
+        )
+        .hidden_legend_constraints((Percentage(50), Percentage(50)));
+    frame.render_widget(chart, rect);
+}
@@ -27,18 +27,25 @@ use ratatui::widgets::{
 };
 use ratatui::{Frame, symbols};
 use ratisui_core::bus::{GlobalEvent, Message, publish_event, publish_msg};
+use ratisui_core::constants::KEY_SEPARATOR;
 use ratisui_core::marcos::KeyAsserter;
-use ratisui_core::redis_opt::{async_redis_opt, redis_operations, spawn_redis_opt};
+use ratisui_core::redis_opt::{async_redis_opt, is_read_only, redis_operations, spawn_redis_opt, Disposable, DisposableMonitor, ScanCursor};
+use ratisui_core::server::{keyevent_flags, KeyNotification};
 use ratisui_core::theme::get_color;
 use ratisui_core::utils::{ContentType, deserialize_bytes, clear_frame};
-use ratisui_core::utils::{bytes_to_string, clean_text_area};
+use ratisui_core::utils::{bytes_to_string, clean_text_area, glob_match};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 use tokio::join;
 use tui_textarea::TextArea;
 use tui_tree_widget::{Tree, TreeItem, TreeState};
 use ratisui_core::mouse::MouseEventHelper;
 
 const PAGE_SIZE: isize = 100;
+/// Bursts of notifications on the selected key reload its value at most this often.
+const LIVE_RELOAD_INTERVAL: Duration = Duration::from_millis(500);
 
 pub struct ExplorerTab {
     pub current_screen: CurrentScreen,
@@ -47,6 +54,7 @@ pub struct ExplorerTab {
     show_rename: bool,
     show_delete_popup: bool,
     show_search_popup: bool,
+    show_live_popup: bool,
     filter_mod: FilterMod,
     scan_size: u16,
     try_format: bool,
@@ -54,8 +62,18 @@ pub struct ExplorerTab {
     create_key_form: Form,
     rename_key_text_area: TextArea<'static>,
     scan_keys_result: Vec<RedisKey>,
+    scan_pattern: String,
+    scan_cursor: Option<ScanCursor>,
+    scan_cancel: Option<Arc<AtomicBool>>,
+    scan_id: u64,
+    scanning: bool,
     tree_state: TreeState<String>,
     tree_items: Vec<TreeItem<'static, String>>,
+    tree_root: TreeNode,
+    live: Arc<RwLock<Option<DisposableMonitor>>>,
+    live_mode: bool,
+    live_reload_pending: bool,
+    last_live_reload: Option<Instant>,
     ft_search_panel: FtSearchPanel<'static>,
     redis_separator: String,
     selected_key: Option<RedisKey>,
@@ -71,6 +89,7 @@ pub struct ExplorerTab {
     offset: isize,
 
     has_search_module: bool,
+    read_only: bool,
 
     tree_rect: Rect,
     value_rect: Rect,
@@ -82,6 +101,8 @@ pub struct Data {
     key_name: String,
     data_flags: DataFlags,
     scan_keys_result: Vec<RedisKey>,
+    scan_id: u64,
+    scan_cursor: Option<ScanCursor>,
     selected_string_value: Option<(String, Option<ContentType>)>,
     selected_list_value: Option<Vec<String>>,
     selected_set_value: Option<Vec<String>>,
@@ -93,6 +114,8 @@ pub struct Data {
     key_size: Option<usize>,
     length: Option<usize>,
     ttl: Option<u64>,
+    /// Every master already publishes keyevent notifications.
+    keyevents_enabled: bool,
 }
 
 impl Data {
@@ -118,6 +141,10 @@ bitflags! {
         const TTL = 0b0000_0100_0000_0000;
 
         const TIME_SERIES_VALUE = 0b0000_1000_0000_0000;
+        const SCAN_MORE = 0b0001_0000_0000_0000;
+        const SCAN_DONE = 0b0010_0000_0000_0000;
+        const RESCAN = 0b0100_0000_0000_0000;
+        const LIVE_CHECK = 0b1000_0000_0000_0000;
     }
 }
 
@@ -185,6 +212,25 @@ impl TreeNode {
             }
         }
     }
+
+    /// Remove the key at `path` and prune the prefixes left empty, returns if the key was there.
+    fn remove(&mut self, path: &[&str]) -> bool {
+        let Some((first, rest)) = path.split_first() else {
+            return false;
+        };
+        let Some(child) = self.children.get_mut(*first) else {
+            return false;
+        };
+        let removed = if rest.is_empty() {
+            child.key_type.take().is_some()
+        } else {
+            child.remove(rest)
+        };
+        if child.key_type.is_none() && child.children.is_empty() {
+            self.children.remove(*first);
+        }
+        removed
+    }
 }
 
 fn build_tree_items(node: &TreeNode) -> Vec<TreeItem<'static, String>> {
@@ -263,6 +309,7 @@ impl ExplorerTab {
             show_rename: false,
             show_delete_popup: false,
             show_search_popup: false,
+            show_live_popup: false,
             filter_mod: FilterMod::Fuzzy,
             scan_size: 2_000,
             try_format: false,
@@ -270,10 +317,20 @@ impl ExplorerTab {
             create_key_form: Form::default().title("Create Key"),
             rename_key_text_area,
             scan_keys_result: vec![],
+            scan_pattern: "*".to_string(),
+            scan_cursor: None,
+            scan_cancel: None,
+            scan_id: 0,
+            scanning: false,
             tree_state: Default::default(),
             tree_items: vec![],
+            tree_root: TreeNode::new(),
+            live: Arc::new(RwLock::new(None)),
+            live_mode: false,
+            live_reload_pending: false,
+            last_live_reload: None,
             ft_search_panel: FtSearchPanel::new(),
-            redis_separator: ":".to_string(),
+            redis_separator: KEY_SEPARATOR.to_string(),
             selected_key: None,
             selected_raw_value: None,
             selected_list_value: None,
@@ -286,6 +343,7 @@ impl ExplorerTab {
             data_receiver: rx,
             offset: 0,
             has_search_module: false,
+            read_only: false,
 
             tree_rect: Default::default(),
             value_rect: Default::default(),
@@ -296,9 +354,34 @@ impl ExplorerTab {
     fn update_data(&mut self, data: Data) {
         let flags = data.data_flags;
         if flags.contains(DataFlags::SCAN_KEYS) {
-            self.scan_keys_result = data.scan_keys_result;
+            self.scan_keys_result = data.scan_keys_result.clone();
             let _ = self.build_tree_items();
         }
+        if flags.contains(DataFlags::SCAN_MORE) && data.scan_id == self.scan_id {
+            self.scan_keys_result.extend(data.scan_keys_result.clone());
+            self.scan_cursor = data.scan_cursor.clone();
+            let _ = self.build_tree_items();
+        }
+        if flags.contains(DataFlags::SCAN_DONE) && data.scan_id == self.scan_id {
+            self.scan_cursor = data.scan_cursor.clone();
+            self.scanning = false;
+            self.scan_cancel = None;
+        }
+        if flags.contains(DataFlags::RESCAN) {
+            if let Some(first_line) = self.get_filter_text() {
+                let _ = self.do_scan(first_line);
+            }
+        }
+        if flags.contains(DataFlags::LIVE_CHECK) {
+            if data.keyevents_enabled {
+                let _ = self.start_live(false);
+            } else if self.read_only {
+                let _ = publish_msg(Message::warning("Keyevent notifications are disabled on the server, read-only mode will not enable them"));
+                let _ = self.start_live(false);
+            } else {
+                self.show_live_popup = true;
+            }
+        }
         if let Some(redis_key) = &mut self.selected_key
             && redis_key.name == data.key_name
         {
@@ -524,12 +607,52 @@ impl ExplorerTab {
         }
     }
 
+    fn render_live_popup(&mut self, frame: &mut Frame, area: Rect) {
+        let popup_area = centered_rect(40, 20, area);
+        let mut text = Text::default();
+        text.push_line(
+            Line::raw("notify-keyspace-events")
+                .alignment(Alignment::Center)
+                .underlined(),
+        );
+        text.push_line(Line::default());
+        text.push_line(Line::raw("The server does not publish keyevent notifications.").alignment(Alignment::Center));
+        text.push_line(
+            Line::raw("Enable them until it restarts?")
+                .alignment(Alignment::Center)
+                .bold(),
+        );
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center);
+        let live_popup = Popup::new(paragraph)
+            .title(String::from(" [Enter] Enable & Start | [Esc] Cancel "))
+            .title_position(Position::Bottom)
+            .borders(Borders::ALL)
+            .border_set(symbols::border::DOUBLE)
+            .style(Style::default().bg(get_color(|t| &t.tab.explorer.accent)));
+        frame.render_widget(live_popup, popup_area);
+    }
+
+    fn scan_status(&self) -> String {
+        if self.scanning {
+            let (finished, total) = self.scan_cursor.as_ref().map_or((0, 0), |c| c.progress());
+            format!(" Scanning {finished}/{total} nodes done, [Esc] Cancel ")
+        } else if self.scan_cursor.as_ref().is_some_and(|c| !c.is_finished()) {
+            " [m] Load More ".to_string()
+        } else {
+            String::new()
+        }
+    }
+
     fn render_tree(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         let tree = Tree::new(&self.tree_items)?
             .block(
                 Block::bordered()
-                    .title("Keys")
-                    .title_bottom("")
+                    .title(format!(
+                        "Keys ({}){}",
+                        self.scan_keys_result.len(),
+                        if self.live_mode { " [live]" } else { "" }
+                    ))
+                    .title_bottom(self.scan_status())
                     .border_style(self.border_color(KeysTree)),
             )
             .experimental_scrollbar(Some(
@@ -550,6 +673,14 @@ impl ExplorerTab {
         Ok(())
     }
 
+    /// Warn and return true when the active database does not accept writes.
+    fn reject_write(&self) -> bool {
+        if self.read_only {
+            let _ = publish_msg(Message::warning("Read-only mode, write operations are disabled."));
+        }
+        self.read_only
+    }
+
     fn toggle_screen(&mut self, screen: CurrentScreen) {
         self.current_screen = screen;
     }
@@ -568,21 +699,75 @@ impl ExplorerTab {
             FilterMod::Pattern => filter_text.clone(),
         };
 
+        self.scan_pattern = pattern;
+        self.scan_keys_result.clear();
+        let _ = self.build_tree_items();
+        self.start_scan(ScanCursor::default())
+    }
+
+    /// Continue the last scan from where it stopped.
+    fn load_more(&mut self) -> Result<()> {
+        if self.scanning {
+            return Ok(());
+        }
+        if let Some(cursor) = self.scan_cursor.clone()
+            && !cursor.is_finished()
+        {
+            self.start_scan(cursor)?;
+        }
+        Ok(())
+    }
+
+    fn cancel_scan(&mut self) {
+        if let Some(cancel) = self.scan_cancel.take() {
+            cancel.store(true, Ordering::Relaxed);
+        }
+        self.scanning = false;
+    }
+
+    /// Stream batches of keys into the tree until `scan_size` more keys
+    /// are loaded, every cursor is exhausted or the scan is cancelled.
+    fn start_scan(&mut self, cursor: ScanCursor) -> Result<()> {
+        self.cancel_scan();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.scan_cancel = Some(cancel.clone());
+        self.scan_id += 1;
+        self.scanning = true;
+
+        let scan_id = self.scan_id;
+        let pattern = self.scan_pattern.clone();
         let sender = self.data_sender.clone();
-        let size_clone = self.scan_size.clone();
+        let size_clone = self.scan_size as usize;
         spawn_redis_opt(move |operations| async move {
-            let mut data = Data::default();
-            let keys = operations.scan(pattern, size_clone as usize).await;
-            if let Ok(keys) = keys {
-                let vec = keys
-                    .iter()
-                    .map(|s| RedisKey::new(s, "unknown"))
-                    .collect::<Vec<RedisKey>>();
-                data.add(DataFlags::SCAN_KEYS);
-                data.scan_keys_result = vec;
+            let mut cursor = cursor;
+            let mut loaded = 0;
+            let mut result = Ok(());
+            while loaded < size_clone && !cursor.is_finished() && !cancel.load(Ordering::Relaxed) {
+                match operations.scan_batch(pattern.clone(), size_clone, &mut cursor).await {
+                    Ok(keys) => {
+                        loaded += keys.len();
+                        let mut data = Data::default();
+                        data.add(DataFlags::SCAN_MORE);
+                        data.scan_id = scan_id;
+                        data.scan_keys_result = keys
+                            .iter()
+                            .map(|s| RedisKey::new(s, "unknown"))
+                            .collect::<Vec<RedisKey>>();
+                        data.scan_cursor = Some(cursor.clone());
+                        sender.send(data)?;
+                    }
+                    Err(e) => {
+                        result = Err(e);
+                        break;
+                    }
+                }
             }
-            sender.send(data.clone())?;
-            Ok(())
+            let mut data = Data::default();
+            data.add(DataFlags::SCAN_DONE);
+            data.scan_id = scan_id;
+            data.scan_cursor = Some(cursor);
+            sender.send(data)?;
+            result
         })?;
         Ok(())
     }
@@ -713,9 +898,161 @@ impl ExplorerTab {
             root.insert(&parts, &data.key_type, "", self.redis_separator.clone());
         }
         self.tree_items = build_tree_items(&root);
+        self.tree_root = root;
         Ok(())
     }
 
+    /// Check `notify-keyspace-events` first, live mode starts once the answer arrives.
+    fn toggle_live(&mut self) -> Result<()> {
+        if self.live_mode {
+            self.stop_live();
+            publish_msg(Message::info("Live mode stopped"))?;
+            return Ok(());
+        }
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            let mut data = Data::default();
+            data.add(DataFlags::LIVE_CHECK);
+            data.keyevents_enabled = match operations.notify_keyspace_events().await {
+                Ok(events) => events.iter().all(|(_, flags)| keyevent_flags(flags).is_none()),
+                Err(e) => {
+                    // CONFIG is often renamed or disabled on managed servers, subscribe anyway
+                    publish_msg(Message::warning(format!("Cannot read notify-keyspace-events: {e}")))?;
+                    true
+                }
+            };
+            sender.send(data)?;
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn start_live(&mut self, enable: bool) -> Result<()> {
+        self.stop_live();
+        self.live_mode = true;
+        let arc = Arc::clone(&self.live);
+        spawn_redis_opt(move |operations| async move {
+            if enable {
+                match operations.enable_keyevent_notifications().await {
+                    Ok(changed) if changed > 0 => {
+                        publish_msg(Message::info(format!("Enabled keyevent notifications on {changed} node(s) until restart")))?;
+                    }
+                    Ok(_) => {}
+                    Err(e) => publish_msg(Message::error(format!("Cannot enable keyevent notifications: {e}")))?,
+                }
+            }
+            let pattern = format!("__keyevent@{}__:*", operations.database().db);
+            // Notifications are read from the monitor, only the shutdown prompt goes to this sender.
+            let monitor = match operations.psubscribe(pattern, unbounded().0).await {
+                Ok(monitor) => monitor,
+                Err(e) => {
+                    publish_msg(Message::error(format!("Cannot subscribe to keyevent notifications: {e}")))?;
+                    return Ok(());
+                }
+            };
+            if let Ok(mut live) = arc.write() {
+                *live = Some(monitor);
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    fn stop_live(&mut self) {
+        self.live_mode = false;
+        self.live_reload_pending = false;
+        if let Ok(mut live) = self.live.write() {
+            if let Some(monitor) = live.as_mut() {
+                let _ = monitor.dispose();
+            }
+            *live = None;
+        }
+    }
+
+    /// Apply pending keyspace notifications to the tree, returns if anything changed.
+    fn handle_live_notifications(&mut self) -> bool {
+        let mut notifications = vec![];
+        if let Ok(mut live) = self.live.write()
+            && let Some(monitor) = live.as_mut()
+        {
+            if !self.live_mode {
+                // stopped while the subscription was still starting
+                let _ = monitor.dispose();
+                *live = None;
+                return false;
+            }
+            while let Ok(value) = monitor.try_recv() {
+                notifications.extend(KeyNotification::parse(&value));
+            }
+        }
+
+        let mut tree_changed = false;
+        for notification in notifications {
+            let key = notification.key;
+            let parts: Vec<&str> = key.split(self.redis_separator.as_str()).collect();
+            let exists = self.scan_keys_result.iter().any(|redis_key| redis_key.name == key);
+            let selected = self.selected_key.as_ref().is_some_and(|redis_key| redis_key.name == key);
+            if notification.is_removal() {
+                if exists {
+                    self.scan_keys_result.retain(|redis_key| redis_key.name != key);
+                    self.tree_root.remove(&parts);
+                    tree_changed = true;
+                }
+                if selected {
+                    self.clear_selected_value();
+                    self.selected_key = None;
+                    self.live_reload_pending = false;
+                }
+                continue;
+            }
+            if !exists && glob_match(&self.scan_pattern, &key) {
+                self.tree_root.insert(&parts, "unknown", "", self.redis_separator.clone());
+                self.scan_keys_result.push(RedisKey::new(key.as_str(), "unknown"));
+                tree_changed = true;
+            }
+            if selected {
+                self.live_reload_pending = true;
+            }
+        }
+        if tree_changed {
+            self.tree_items = build_tree_items(&self.tree_root);
+        }
+
+        let mut reloaded = false;
+        if self.live_reload_pending
+            && self.last_live_reload.is_none_or(|last| last.elapsed() >= LIVE_RELOAD_INTERVAL)
+            && let Some(redis_key) = &self.selected_key
+        {
+            self.load_key(redis_key.name.clone(), self.offset);
+            self.live_reload_pending = false;
+            self.last_live_reload = Some(Instant::now());
+            reloaded = true;
+        }
+        tree_changed || reloaded
+    }
+
+    fn clear_selected_value(&mut self) {
+        self.selected_raw_value = None;
+        self.selected_list_value = None;
+        self.selected_set_value = None;
+        self.selected_zset_value = None;
+        self.selected_hash_value = None;
+        self.selected_stream_value = None;
+        self.selected_time_series_value = None;
+    }
+
+    /// Fetch the information and value of `key_name`, they are applied in [`Self::update_data`].
+    fn load_key(&self, key_name: String, offset: isize) {
+        let sender = self.data_sender.clone();
+        tokio::spawn(async move {
+            let data = Self::do_get_key_info(key_name.clone()).await?;
+            sender.send(data.clone())?;
+            if let Some(key_type) = data.key_type {
+                let data = Self::do_get_value(key_name, key_type, offset).await?;
+                sender.send(data)?;
+            }
+            Ok::<(), Error>(())
+        });
+    }
+
     fn get_filter_text(&self) -> Option<String> {
         self.filter_text_area.lines().get(0).cloned()
     }
@@ -804,36 +1141,14 @@ impl ExplorerTab {
                 {
                     let key_name = redis_key.name.clone();
                     let new_key_name = first_line.trim().to_string();
-                    if let Some(filter_text) = self.get_filter_text() {
-                        let pattern = match self.filter_mod {
-                            FilterMod::Fuzzy => {
-                                let mut matcher = filter_text.clone();
-                                if matcher.is_empty() {
-                                    matcher = "*".to_string();
-                                } else {
-                                    matcher = format!("*{}*", matcher);
-                                }
-                                matcher
-                            }
-                            FilterMod::Pattern => filter_text.clone(),
-                        };
-                        let sender = self.data_sender.clone();
-                        let pattern_clone = pattern.clone();
-                        let size_clone = self.scan_size.clone();
-                        spawn_redis_opt(move |operations| async move {
-                            operations.rename_nx(key_name, new_key_name).await?;
-                            let mut data = Data::default();
-                            let keys = operations.scan(pattern_clone, size_clone as usize).await?;
-                            let vec = keys
-                                .iter()
-                                .map(|s| RedisKey::new(s, "unknown"))
-                                .collect::<Vec<RedisKey>>();
-                            data.add(DataFlags::SCAN_KEYS);
-                            data.scan_keys_result = vec;
-                            sender.send(data.clone())?;
-                            Ok(())
-                        })?;
-                    }
+                    let sender = self.data_sender.clone();
+                    spawn_redis_opt(move |operations| async move {
+                        operations.rename_nx(key_name, new_key_name).await?;
+                        let mut data = Data::default();
+                        data.add(DataFlags::RESCAN);
+                        sender.send(data)?;
+                        Ok(())
+                    })?;
                     self.selected_key = None;
                 }
                 self.show_rename = false;
@@ -895,48 +1210,27 @@ impl ExplorerTab {
                     return Err(anyhow!("Key name must not be blank!"));
                 }
 
-                if let Some(filter_text) = self.get_filter_text() {
-                    let pattern = match self.filter_mod {
-                        FilterMod::Fuzzy => {
-                            let mut matcher = filter_text.clone();
-                            if matcher.is_empty() {
-                                matcher = "*".to_string();
-                            } else {
-                                matcher = format!("*{}*", matcher);
-                            }
-                            matcher
-                        }
-                        FilterMod::Pattern => filter_text.clone(),
-                    };
-                    let sender = self.data_sender.clone();
-                    let size_clone = self.scan_size.clone();
-                    spawn_redis_opt(move |operations| async move {
-                        match key_type {
-                            KeyType::String => operations.set_nx(key_name.clone(), "").await?,
-                            KeyType::Hash => operations.hset_nx(key_name.clone(), "", "").await?,
-                            KeyType::List => operations.lpush(key_name.clone(), "").await?,
-                            KeyType::Set => operations.sadd(key_name.clone(), "").await?,
-                            KeyType::SortedSet => operations.zadd(key_name.clone(), "", 0.0).await?,
-                            KeyType::Stream => operations.xadd(key_name.clone(), "", "").await?,
-                        }
+                let sender = self.data_sender.clone();
+                spawn_redis_opt(move |operations| async move {
+                    match key_type {
+                        KeyType::String => operations.set_nx(key_name.clone(), "").await?,
+                        KeyType::Hash => operations.hset_nx(key_name.clone(), "", "").await?,
+                        KeyType::List => operations.lpush(key_name.clone(), "").await?,
+                        KeyType::Set => operations.sadd(key_name.clone(), "").await?,
+                        KeyType::SortedSet => operations.zadd(key_name.clone(), "", 0.0).await?,
+                        KeyType::Stream => operations.xadd(key_name.clone(), "", "").await?,
+                    }
 
-                        if let Some(ttl) = ttl {
-                            operations.expire(key_name.clone(), ttl as i64).await?;
-                        }
+                    if let Some(ttl) = ttl {
+                        operations.expire(key_name.clone(), ttl as i64).await?;
+                    }
 
-                        let mut data = Data::default();
-                        let keys = operations.scan(pattern, size_clone as usize).await?;
-                        let vec = keys
-                            .iter()
-                            .map(|s| RedisKey::new(s, "unknown"))
-                            .collect::<Vec<RedisKey>>();
-                        data.add(DataFlags::SCAN_KEYS);
-                        data.scan_keys_result = vec;
-                        sender.send(data.clone())?;
+                    let mut data = Data::default();
+                    data.add(DataFlags::RESCAN);
+                    sender.send(data)?;
 
-                        Ok(())
-                    })?;
-                }
+                    Ok(())
+                })?;
                 self.show_create = false;
                 Ok(true)
             }
@@ -970,6 +1264,23 @@ impl ExplorerTab {
         Ok(true)
     }
 
+    fn handle_live_popup_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.kind != KeyEventKind::Press || key_event.modifiers != KeyModifiers::NONE {
+            return Ok(true);
+        }
+
+        match key_event.code {
+            KeyCode::Enter => {
+                self.show_live_popup = false;
+                self.start_live(true)?;
+            }
+            KeyCode::Esc => self.show_live_popup = false,
+            _ => {}
+        }
+
+        Ok(true)
+    }
+
     fn handle_search_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
         let accepted = self.ft_search_panel.handle_key_event(key_event)?;
         if !accepted {
@@ -1039,24 +1350,10 @@ impl ExplorerTab {
                         .find(|redis_key| id.eq(&redis_key.name))
                         .cloned();
                     self.selected_key = option;
-                    self.selected_raw_value = None;
-                    self.selected_list_value = None;
-                    self.selected_set_value = None;
-                    self.selected_zset_value = None;
-                    self.selected_hash_value = None;
-                    self.selected_stream_value = None;
-                    self.selected_time_series_value = None;
+                    self.clear_selected_value();
+                    self.live_reload_pending = false;
                     if self.selected_key.is_some() {
-                        let sender = self.data_sender.clone();
-                        tokio::spawn(async move {
-                            let data = Self::do_get_key_info(id.clone()).await?;
-                            sender.send(data.clone())?;
-                            if let Some(key_type) = data.key_type {
-                                let data = Self::do_get_value(id.clone(), key_type, 0).await?;
-                                sender.send(data)?;
-                            }
-                            Ok::<(), Error>(())
-                        });
+                        self.load_key(id, 0);
                     }
                 }
             }
@@ -1090,24 +1387,10 @@ impl ExplorerTab {
                         .find(|redis_key| id.eq(&redis_key.name))
                         .cloned();
                     self.selected_key = option;
-                    self.selected_raw_value = None;
-                    self.selected_list_value = None;
-                    self.selected_set_value = None;
-                    self.selected_zset_value = None;
-                    self.selected_hash_value = None;
-                    self.selected_stream_value = None;
-                    self.selected_time_series_value = None;
+                    self.clear_selected_value();
+                    self.live_reload_pending = false;
                     if self.selected_key.is_some() {
-                        let sender = self.data_sender.clone();
-                        tokio::spawn(async move {
-                            let data = Self::do_get_key_info(id.clone()).await?;
-                            sender.send(data.clone())?;
-                            if let Some(key_type) = data.key_type {
-                                let data = Self::do_get_value(id.clone(), key_type, 0).await?;
-                                sender.send(data)?;
-                            }
-                            Ok::<(), Error>(())
-                        });
+                        self.load_key(id, 0);
                     }
                 }
             }
@@ -1385,6 +1668,9 @@ impl Renderable for ExplorerTab {
         if self.show_delete_popup {
             self.render_delete_popup(frame, rect);
         }
+        if self.show_live_popup {
+            self.render_live_popup(frame, rect);
+        }
         if self.show_create {
             self.render_create_key_form(frame, frame.area())?;
         }
@@ -1406,6 +1692,9 @@ impl Renderable for ExplorerTab {
             elements = self.create_key_form.footer_elements();
             elements.push(("Enter", "Create"));
             elements.push(("Esc", "Close"));
+        } else if self.show_live_popup {
+            elements.push(("Enter", "Enable & Start"));
+            elements.push(("Esc", "Cancel"));
         } else if self.show_search_popup {
             elements = self.ft_search_panel.footer_elements();
             elements.push(("Enter", "Apply"));
@@ -1413,12 +1702,20 @@ impl Renderable for ExplorerTab {
         } else {
             if self.current_screen == KeysTree {
                 elements.push(("/", "Scan"));
+                if self.scanning {
+                    elements.push(("Esc", "Cancel Scan"));
+                } else if self.scan_cursor.as_ref().is_some_and(|c| !c.is_finished()) {
+                    elements.push(("m", "Load More"));
+                }
                 if self.has_search_module {
                     elements.push(("^k", "FT.Search"));
                 }
-                elements.push(("c", "Create"));
-                elements.push(("d/Del", "Delete"));
-                elements.push(("r", "Rename"));
+                if !self.read_only {
+                    elements.push(("c", "Create"));
+                    elements.push(("d/Del", "Delete"));
+                    elements.push(("r", "Rename"));
+                }
+                elements.push(("n", if self.live_mode { "Stop Live" } else { "Live" }));
                 elements.push(("↓/j", "Down"));
                 elements.push(("↑/k", "Up"));
                 elements.push(("←/h", "Close"));
@@ -1493,8 +1790,9 @@ impl Renderable for ExplorerTab {
                 needed = true;
             }
         }
+        let live_needed = self.handle_live_notifications();
         let ft_search_panel_needed = self.ft_search_panel.handle_data()?;
-        Ok(needed || ft_search_panel_needed)
+        Ok(needed || live_needed || ft_search_panel_needed)
     }
 }
 
@@ -1507,6 +1805,9 @@ impl Listenable for ExplorerTab {
         if self.show_delete_popup {
             return self.handle_delete_popup_key_event(key_event);
         }
+        if self.show_live_popup {
+            return self.handle_live_popup_key_event(key_event);
+        }
         if self.show_filter {
             return self.handle_filter_key_event(key_event);
         }
@@ -1591,22 +1892,38 @@ impl Listenable for ExplorerTab {
                     return Ok(true);
                 };
                 match key_event.code {
+                    KeyCode::Esc if self.scanning => {
+                        self.cancel_scan();
+                        return Ok(true);
+                    }
+                    KeyCode::Char('m') => {
+                        self.load_more()?;
+                        return Ok(true);
+                    }
                     KeyCode::Char('/') => {
                         self.show_filter = true;
                         return Ok(true);
                     }
+                    KeyCode::Char('n') => {
+                        self.toggle_live()?;
+                        return Ok(true);
+                    }
                     KeyCode::Char('d') | KeyCode::Delete => {
-                        if self.selected_key.is_some() {
+                        if self.selected_key.is_some() && !self.reject_write() {
                             self.show_delete_popup = true;
                         }
                         return Ok(true);
                     }
                     KeyCode::Char('c') => {
-                        self.show_create = true;
+                        if !self.reject_write() {
+                            self.show_create = true;
+                        }
                         return Ok(true);
                     }
                     KeyCode::Char('r') => {
-                        if let Some(redis_key) = &self.selected_key {
+                        if let Some(redis_key) = &self.selected_key
+                            && !self.reject_write()
+                        {
                             let key_name = redis_key.name.clone();
                             clean_text_area(&mut self.rename_key_text_area);
                             self.rename_key_text_area.insert_str(key_name);
@@ -1696,11 +2013,13 @@ impl Listenable for ExplorerTab {
     fn on_app_event(&mut self, app_event: AppEvent) -> Result<()> {
         match app_event {
             AppEvent::InitConfig(configuration, _) => {
+                self.read_only = is_read_only();
                 self.scan_size = configuration.scan_size;
                 self.try_format = configuration.try_format;
             }
             AppEvent::Reset => {
                 self.show_delete_popup = false;
+                self.show_live_popup = false;
                 self.show_filter = false;
                 self.show_search_popup = false;
                 self.filter_text_area = TextArea::default();
@@ -1710,6 +2029,9 @@ impl Listenable for ExplorerTab {
             }
             AppEvent::Bus(global_event) => match global_event {
                 GlobalEvent::ClientChanged => {
+                    self.stop_live();
+                    self.show_live_popup = false;
+                    self.read_only = is_read_only();
                     if let Some(first_line) = self.get_filter_text() {
                         self.do_scan(first_line)?;
                         if let Some(ref redis_opt) = redis_operations() {
@@ -1,3 +1,5 @@
 pub mod explorer;
 pub mod cli;
+pub mod scripts;
+pub mod dashboard;
 pub mod logger;
\ No newline at end of file
@@ -0,0 +1,569 @@
+use crate::app::{AppEvent, Listenable, Renderable, TabImplementation};
+use crate::components::console_output::{ConsoleData, OutputKind};
+use crate::components::raw_value::raw_value_to_highlight_text_with_content_type;
+use crate::tabs::cli::{error_value, value_to_lines};
+use anyhow::{Error, Result};
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use deadpool_redis::redis::{Cmd, Value};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent};
+use ratatui::layout::Constraint::{Fill, Length, Percentage};
+use ratatui::layout::{Layout, Rect};
+use ratatui::prelude::{Line, Stylize, Text};
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, WidgetRef};
+use ratatui::Frame;
+use ratisui_core::bus::{publish_msg, GlobalEvent, Message};
+use ratisui_core::configuration::CliOutputFormatKind;
+use ratisui_core::marcos::KeyAsserter;
+use ratisui_core::mouse::MouseEventHelper;
+use ratisui_core::redis_opt::{is_read_only, spawn_redis_opt, Function, FunctionLibrary, RedisOperations};
+use ratisui_core::theme::get_color;
+use ratisui_core::utils::{split_args, try_decode_arg, ContentType};
+use std::borrow::Cow;
+use tui_textarea::TextArea;
+
+/// Write Lua scripts and Redis functions, run them with `EVAL`/`EVALSHA` and `FCALL`.
+pub struct ScriptsTab {
+    editing: Option<Editing>,
+    script_text_area: TextArea<'static>,
+    keys_text_area: TextArea<'static>,
+    argv_text_area: TextArea<'static>,
+    /// Highlighted script shown while it is not being edited, rebuilt after changes.
+    highlighted_script: Option<Text<'static>>,
+    libraries: Vec<FunctionLibrary>,
+    function_state: ListState,
+    /// Script sent with `SCRIPT LOAD` and its sha1, `EVALSHA` is used while the script is unchanged.
+    loaded_script: Option<(String, String)>,
+    console_capacity: usize,
+    console_data: ConsoleData<'static>,
+    output_format: CliOutputFormatKind,
+    data_sender: Sender<ScriptData>,
+    data_receiver: Receiver<ScriptData>,
+
+    functions_area: Rect,
+    output_area: Rect,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Editing {
+    Script,
+    Keys,
+    Argv,
+}
+
+enum ScriptData {
+    Reply(Value),
+    /// Script and its sha1 returned by `SCRIPT LOAD`.
+    Loaded(String, String),
+    Libraries(Vec<FunctionLibrary>),
+}
+
+impl TabImplementation for ScriptsTab {
+    fn highlight(&self) -> Color {
+        get_color(|t| &t.tab.scripts.highlight)
+    }
+
+    fn title(&self) -> Line<'static> {
+        "  Scripts   "
+            .fg(get_color(|t| &t.tab.title))
+            .bg(get_color(|t| &t.tab.scripts.accent))
+            .into()
+    }
+}
+
+impl ScriptsTab {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded();
+        let default_console_capacity = 3000;
+        Self {
+            editing: None,
+            script_text_area: new_script_text_area(vec![]),
+            keys_text_area: new_param_text_area("key1 key2"),
+            argv_text_area: new_param_text_area("arg1 arg2"),
+            highlighted_script: None,
+            libraries: vec![],
+            function_state: ListState::default(),
+            loaded_script: None,
+            console_capacity: default_console_capacity,
+            console_data: ConsoleData::new(default_console_capacity),
+            output_format: CliOutputFormatKind::default(),
+            data_sender: tx,
+            data_receiver: rx,
+            functions_area: Rect::default(),
+            output_area: Rect::default(),
+        }
+    }
+
+    fn script(&self) -> String {
+        self.script_text_area.lines().join("\n")
+    }
+
+    fn set_script(&mut self, script: &str) {
+        self.script_text_area = new_script_text_area(script.lines().map(String::from).collect());
+        self.highlighted_script = None;
+    }
+
+    /// Library rows followed by their function rows, as indexes into `libraries`.
+    fn function_rows(&self) -> Vec<(usize, Option<usize>)> {
+        let mut rows = vec![];
+        for (i, library) in self.libraries.iter().enumerate() {
+            rows.push((i, None));
+            for j in 0..library.functions.len() {
+                rows.push((i, Some(j)));
+            }
+        }
+        rows
+    }
+
+    fn selected_row(&self) -> Option<(&FunctionLibrary, Option<&Function>)> {
+        let rows = self.function_rows();
+        let (i, j) = self.function_state.selected().and_then(|selected| rows.get(selected).copied())?;
+        let library = self.libraries.get(i)?;
+        match j {
+            Some(j) => library.functions.get(j).map(|function| (library, Some(function))),
+            None => Some((library, None)),
+        }
+    }
+
+    fn next_function(&mut self) {
+        let len = self.function_rows().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.function_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.function_state.select(Some(i));
+    }
+
+    fn previous_function(&mut self) {
+        let len = self.function_rows().len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.function_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.function_state.select(Some(i));
+    }
+
+    fn update_libraries(&mut self, libraries: Vec<FunctionLibrary>) {
+        self.libraries = libraries;
+        let len = self.function_rows().len();
+        let selected = match self.function_state.selected() {
+            _ if len == 0 => None,
+            Some(i) => Some(i.min(len - 1)),
+            None => Some(0),
+        };
+        self.function_state.select(selected);
+    }
+
+    fn refresh_functions(&mut self) -> Result<()> {
+        let sender = self.data_sender.clone();
+        spawn_redis_opt(move |operations| async move {
+            match operations.function_list().await {
+                Ok(libraries) => sender.send(ScriptData::Libraries(libraries))?,
+                Err(e) => {
+                    let _ = publish_msg(Message::error(format!("Failed to list functions: {}", e)));
+                }
+            }
+            Ok::<(), Error>(())
+        })
+    }
+
+    /// `EVAL` the script, or `EVALSHA` when it is unchanged since `SCRIPT LOAD`.
+    fn run_script(&mut self) {
+        let script = self.script();
+        if script.trim().is_empty() {
+            self.console_data.push_err("ERR script is empty");
+            self.flush_output();
+            return;
+        }
+        let read_only = is_read_only();
+        match &self.loaded_script {
+            Some((loaded, sha)) if loaded == &script => {
+                let sha = sha.clone();
+                self.call(if read_only { "EVALSHA_RO" } else { "EVALSHA" }, sha);
+            }
+            _ => self.call(if read_only { "EVAL_RO" } else { "EVAL" }, script),
+        }
+    }
+
+    /// `FCALL` the selected function, a selected library is opened in the editor instead.
+    fn call_selected(&mut self) {
+        let Some((library, function)) = self.selected_row() else {
+            return;
+        };
+        match function {
+            Some(function) => {
+                let command = if is_read_only() || function.is_read_only() { "FCALL_RO" } else { "FCALL" };
+                let name = function.name.clone();
+                self.call(command, name);
+            }
+            None => {
+                let code = library.code.clone();
+                self.set_script(&code);
+            }
+        }
+    }
+
+    /// Send `<command> <target> numkeys key [key ...] arg [arg ...]`.
+    fn call(&mut self, command: &str, target: String) {
+        let keys = split_args(self.keys_text_area.lines().join(" "));
+        let argv = split_args(self.argv_text_area.lines().join(" "));
+        let params = keys.iter().chain(argv.iter()).cloned().collect::<Vec<_>>().join(" ");
+        self.console_data.push(
+            OutputKind::CMD,
+            format!(">_ {command} {} {} {params}", summary(&target), keys.len()),
+        );
+        let cmd = match script_cmd(command, &target, &keys, &argv) {
+            Ok(cmd) => cmd,
+            Err(e) => {
+                self.console_data.push_err(format!("{}", e));
+                self.flush_output();
+                return;
+            }
+        };
+        let sender = self.data_sender.clone();
+        self.spawn(move |operations| async move {
+            let reply = operations.cmd(cmd).await.unwrap_or_else(error_value);
+            sender.send(ScriptData::Reply(reply))?;
+            Ok(())
+        });
+    }
+
+    /// `SCRIPT LOAD` the script, sources starting with a `#!lua` shebang are loaded as a function library.
+    fn load_script(&mut self) {
+        let script = self.script();
+        if script.trim().is_empty() {
+            self.console_data.push_err("ERR script is empty");
+            self.flush_output();
+            return;
+        }
+        let is_library = script.starts_with("#!");
+        let command = if is_library { "FUNCTION LOAD REPLACE" } else { "SCRIPT LOAD" };
+        self.console_data.push(OutputKind::CMD, format!(">_ {command} {}", summary(&script)));
+        if is_read_only() {
+            self.console_data.push_err(format!("(read-only) {command} is a write command, rejected"));
+            self.flush_output();
+            return;
+        }
+        let sender = self.data_sender.clone();
+        self.spawn(move |operations| async move {
+            let mut cmd = Cmd::new();
+            if is_library {
+                cmd.arg("FUNCTION").arg("LOAD").arg("REPLACE").arg(&script);
+                let reply = operations.cmd(cmd).await.unwrap_or_else(error_value);
+                sender.send(ScriptData::Reply(reply))?;
+                sender.send(ScriptData::Libraries(operations.function_list().await?))?;
+            } else {
+                cmd.arg("SCRIPT").arg("LOAD").arg(&script);
+                match operations.cmd::<String>(cmd).await {
+                    Ok(sha) => sender.send(ScriptData::Loaded(script, sha))?,
+                    Err(e) => sender.send(ScriptData::Reply(error_value(e)))?,
+                }
+            }
+            Ok(())
+        });
+    }
+
+    fn spawn<F, FUT>(&mut self, opt: F)
+    where
+        F: FnOnce(RedisOperations) -> FUT + Send + 'static,
+        FUT: Future<Output = Result<()>> + Send + 'static,
+    {
+        if let Err(e) = spawn_redis_opt(opt) {
+            self.console_data.push_err(format!("{}", e));
+            self.flush_output();
+        } else {
+            self.console_data.build_paragraph();
+        }
+    }
+
+    fn flush_output(&mut self) {
+        self.console_data.push_std("");
+        self.console_data.build_paragraph();
+    }
+
+    fn text_area_mut(&mut self, editing: Editing) -> &mut TextArea<'static> {
+        match editing {
+            Editing::Script => &mut self.script_text_area,
+            Editing::Keys => &mut self.keys_text_area,
+            Editing::Argv => &mut self.argv_text_area,
+        }
+    }
+
+    fn border_color(&self, editing: Editing) -> Color {
+        if self.editing == Some(editing) {
+            get_color(|t| &t.border.highlight)
+        } else {
+            get_color(|t| &t.border.default)
+        }
+    }
+
+    fn render_functions(&mut self, frame: &mut Frame, rect: Rect) {
+        let items = self
+            .function_rows()
+            .into_iter()
+            .filter_map(|(i, j)| {
+                let library = self.libraries.get(i)?;
+                let line = match j {
+                    None => Line::from(vec![
+                        Span::raw(library.name.clone()).bold(),
+                        Span::raw(format!(" {}", library.engine)).dim(),
+                    ]),
+                    Some(j) => {
+                        let function = library.functions.get(j)?;
+                        let mut spans = vec![Span::raw(format!("  {}", function.name))];
+                        if !function.flags.is_empty() {
+                            spans.push(Span::raw(format!(" [{}]", function.flags.join(","))).dim());
+                        }
+                        Line::from(spans)
+                    }
+                };
+                Some(ListItem::new(line))
+            })
+            .collect::<Vec<_>>();
+        let border_color = if self.editing.is_none() {
+            get_color(|t| &t.border.highlight)
+        } else {
+            get_color(|t| &t.border.default)
+        };
+        let list = List::new(items)
+            .block(
+                Block::bordered()
+                    .title("Functions")
+                    .border_style(Style::default().fg(border_color)),
+            )
+            .highlight_style(
+                Style::default()
+                    .fg(get_color(|t| &t.tab.title))
+                    .bg(get_color(|t| &t.tab.scripts.highlight)),
+            );
+        frame.render_stateful_widget(list, rect, &mut self.function_state);
+    }
+
+    fn render_script(&mut self, frame: &mut Frame, rect: Rect) {
+        let title = match &self.loaded_script {
+            Some((loaded, sha)) if loaded == &self.script() => format!("Script (sha: {sha})"),
+            _ => "Script".to_string(),
+        };
+        let block = Block::bordered()
+            .title(title)
+            .border_style(Style::default().fg(self.border_color(Editing::Script)));
+        if self.editing == Some(Editing::Script) {
+            self.script_text_area.set_block(block);
+            frame.render_widget(&self.script_text_area, rect);
+        } else {
+            let script = self.script();
+            let text = self.highlighted_script.get_or_insert_with(|| {
+                let (text, _) = raw_value_to_highlight_text_with_content_type(Cow::from(script), Some(ContentType::Lua), false);
+                text
+            });
+            frame.render_widget(Paragraph::new(text.clone()).block(block), rect);
+        }
+    }
+
+    fn render_param(&mut self, frame: &mut Frame, rect: Rect, editing: Editing) {
+        let title = if editing == Editing::Keys { "KEYS" } else { "ARGV" };
+        let block = Block::bordered()
+            .title(title)
+            .border_style(Style::default().fg(self.border_color(editing)));
+        let text_area = self.text_area_mut(editing);
+        text_area.set_block(block);
+        frame.render_widget(&*text_area, rect);
+    }
+
+    fn render_output(&mut self, frame: &mut Frame, rect: Rect) {
+        let block = Block::default().borders(Borders::TOP).title("Output");
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+        self.console_data.update(&inner);
+        self.console_data.render_ref(inner, frame.buffer_mut());
+    }
+}
+
+impl Renderable for ScriptsTab {
+    fn render_frame(&mut self, frame: &mut Frame, rect: Rect) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let [top_area, output_area] = Layout::vertical([Percentage(60), Fill(1)]).areas(rect);
+        let [functions_area, script_area] = Layout::horizontal([Length(32), Fill(1)]).areas(top_area);
+        let [editor_area, params_area] = Layout::vertical([Fill(1), Length(3)]).areas(script_area);
+        let [keys_area, argv_area] = Layout::horizontal([Fill(1), Fill(1)]).areas(params_area);
+        self.functions_area = functions_area;
+        self.output_area = output_area;
+
+        self.render_functions(frame, functions_area);
+        self.render_script(frame, editor_area);
+        self.render_param(frame, keys_area, Editing::Keys);
+        self.render_param(frame, argv_area, Editing::Argv);
+        self.render_output(frame, output_area);
+        Ok(())
+    }
+
+    fn footer_elements(&self) -> Vec<(&str, &str)> {
+        let mut elements = vec![];
+        match self.editing {
+            Some(Editing::Script) => elements.push(("Esc", "Normal")),
+            Some(_) => {
+                elements.push(("Tab", "KEYS/ARGV"));
+                elements.push(("Esc", "Normal"));
+            }
+            None => {
+                elements.push(("i", "Edit"));
+                elements.push(("p", "KEYS/ARGV"));
+                elements.push(("r", "Run"));
+                elements.push(("l", "Load"));
+                elements.push(("↓/j", "Down"));
+                elements.push(("↑/k", "Up"));
+                elements.push(("Enter", "FCALL/Open"));
+                elements.push(("f", "Functions"));
+                elements.push(("c", "Clear"));
+            }
+        }
+        elements
+    }
+
+    fn handle_data(&mut self) -> Result<bool> {
+        let mut needed = false;
+        while let Ok(data) = self.data_receiver.try_recv() {
+            match data {
+                ScriptData::Reply(value) => {
+                    let lines = value_to_lines(&self.output_format, &value, 0);
+                    self.console_data.extend(lines);
+                    self.flush_output();
+                }
+                ScriptData::Loaded(script, sha) => {
+                    self.console_data.push_std(sha.clone());
+                    self.flush_output();
+                    self.loaded_script = Some((script, sha));
+                }
+                ScriptData::Libraries(libraries) => self.update_libraries(libraries),
+            }
+            needed = true;
+        }
+        Ok(needed)
+    }
+}
+
+impl Listenable for ScriptsTab {
+    fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<bool> {
+        if key_event.is_c_c() || key_event.kind != KeyEventKind::Press {
+            return Ok(false);
+        }
+        if let Some(editing) = self.editing {
+            match key_event.code {
+                KeyCode::Esc => self.editing = None,
+                KeyCode::Enter if editing != Editing::Script => self.editing = None,
+                KeyCode::Tab | KeyCode::BackTab if editing != Editing::Script => {
+                    self.editing = Some(if editing == Editing::Keys { Editing::Argv } else { Editing::Keys });
+                }
+                _ => {
+                    self.text_area_mut(editing).input(key_event);
+                    if editing == Editing::Script {
+                        self.highlighted_script = None;
+                    }
+                }
+            }
+            return Ok(true);
+        }
+        if !key_event.modifiers.is_empty() {
+            return Ok(false);
+        }
+        match key_event.code {
+            KeyCode::Char('i') => self.editing = Some(Editing::Script),
+            KeyCode::Char('p') => self.editing = Some(Editing::Keys),
+            KeyCode::Char('r') => self.run_script(),
+            KeyCode::Char('l') => self.load_script(),
+            KeyCode::Char('f') | KeyCode::F(5) => self.refresh_functions()?,
+            KeyCode::Char('j') | KeyCode::Down => self.next_function(),
+            KeyCode::Char('k') | KeyCode::Up => self.previous_function(),
+            KeyCode::Enter => self.call_selected(),
+            KeyCode::Char('c') => self.console_data = ConsoleData::new(self.console_capacity),
+            KeyCode::PageUp => self.console_data.scroll_page_up(),
+            KeyCode::PageDown => self.console_data.scroll_page_down(),
+            KeyCode::Home => self.console_data.scroll_start(),
+            KeyCode::End => self.console_data.scroll_end(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn handle_mouse_event(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        let position = mouse_event.as_position();
+        if self.output_area.contains(position) {
+            if mouse_event.is_scroll_down() {
+                self.console_data.scroll_down();
+            } else if mouse_event.is_scroll_up() {
+                self.console_data.scroll_up();
+            }
+            return Ok(true);
+        }
+        if self.functions_area.contains(position) {
+            if mouse_event.is_scroll_down() {
+                self.next_function();
+            } else if mouse_event.is_scroll_up() {
+                self.previous_function();
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn on_app_event(&mut self, app_event: AppEvent) -> Result<()> {
+        match app_event {
+            AppEvent::InitConfig(app_config, _) => {
+                self.output_format = app_config.cli_output_format.clone();
+                self.console_capacity = app_config.console_capacity;
+                self.console_data = ConsoleData::new(self.console_capacity);
+            }
+            AppEvent::Bus(GlobalEvent::ClientChanged) => {
+                // scripts cached by the previous server are unknown to this one
+                self.loaded_script = None;
+                self.update_libraries(vec![]);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Build the command, keys and arguments like `base64#...#` are decoded first.
+fn script_cmd(command: &str, target: &str, keys: &[String], argv: &[String]) -> Result<Cmd> {
+    let mut cmd = Cmd::new();
+    cmd.arg(command).arg(target).arg(keys.len());
+    for arg in keys.iter().chain(argv.iter()) {
+        cmd.arg(try_decode_arg(arg)?);
+    }
+    Ok(cmd)
+}
+
+/// First line of a script for the output, a sha or a function name as is.
+fn summary(target: &str) -> String {
+    let mut lines = target.lines();
+    let first = lines.next().unwrap_or_default();
+    if lines.next().is_some() {
+        format!("\"{first} ...\"")
+    } else {
+        first.to_string()
+    }
+}
+
+fn new_script_text_area(lines: Vec<String>) -> TextArea<'static> {
+    let mut text_area = TextArea::new(lines);
+    text_area.set_placeholder_text("return redis.call('GET', KEYS[1])");
+    text_area.set_cursor_line_style(Style::default());
+    text_area
+}
+
+fn new_param_text_area(placeholder: &str) -> TextArea<'static> {
+    let mut text_area = TextArea::default();
+    text_area.set_placeholder_text(placeholder);
+    text_area.set_cursor_line_style(Style::default());
+    text_area
+}